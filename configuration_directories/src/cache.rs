@@ -0,0 +1,136 @@
+use std::{
+    fs::{self, File, OpenOptions, TryLockError},
+    io::{self, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use non_empty::{path::WritableDir, Parse};
+
+/// A cache key, which is always safe to use as a file name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Parse)]
+#[parse(regex = "^[A-Za-z0-9_-]{1,128}$")]
+pub struct CacheKey(String);
+
+/// A key/value store of blobs in a [`WritableDir`].
+///
+/// The layout is versioned, so a future format can live alongside this one:
+///
+/// ```text
+/// <dir>/cache-v1/.lock       locked while a `Cache` is open
+/// <dir>/cache-v1/objects/    one file per key
+/// <dir>/cache-v1/tmp/        partially written values
+/// ```
+///
+/// Values are written to `tmp/` and renamed into place, so readers never see a
+/// partially written value.
+#[derive(Debug)]
+pub struct Cache {
+    root: PathBuf,
+    _lock: LockFile,
+}
+
+impl Cache {
+    const VERSION: u32 = 1;
+
+    pub fn open(dir: &WritableDir) -> io::Result<Self> {
+        let root = dir.join(format!("cache-v{}", Self::VERSION));
+
+        fs::create_dir_all(root.join("objects"))?;
+        fs::create_dir_all(root.join("tmp"))?;
+
+        let lock = LockFile::acquire(root.join(".lock"))?;
+
+        Ok(Self { root, _lock: lock })
+    }
+
+    pub fn get(&self, key: &CacheKey) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.object(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn put(&self, key: &CacheKey, value: &[u8]) -> io::Result<()> {
+        static NEXT_TMP: AtomicU64 = AtomicU64::new(0);
+
+        let tmp = self.root.join("tmp").join(format!(
+            "{}.{}.{}",
+            key.get(),
+            process::id(),
+            NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let result = File::create(&tmp)
+            .and_then(|mut file| {
+                file.write_all(value)?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&tmp, self.object(key)));
+
+        if result.is_err() {
+            // Best effort; the original error is more useful than this one.
+            let _ = fs::remove_file(&tmp);
+        }
+
+        result
+    }
+
+    /// Removes `key`, returning whether it was present.
+    pub fn evict(&self, key: &CacheKey) -> io::Result<bool> {
+        match fs::remove_file(self.object(key)) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn object(&self, key: &CacheKey) -> PathBuf {
+        self.root.join("objects").join(key.get())
+    }
+}
+
+/// An OS lock on a file, held until drop.
+///
+/// The lock rather than the file's existence is what matters, so a crashed
+/// process never leaves a stale lock behind; the file itself is kept, and only
+/// records the pid of the last holder.
+#[derive(Debug)]
+struct LockFile(File);
+
+impl LockFile {
+    fn acquire(path: PathBuf) -> io::Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::ResourceBusy,
+                    format!("{} is held by another cache", path.display()),
+                ))
+            }
+            Err(TryLockError::Error(e)) => return Err(e),
+        }
+
+        // If this fails, dropping `file` releases the lock again.
+        file.set_len(0)?;
+        writeln!(file, "{}", process::id())?;
+
+        Ok(Self(file))
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        // Closing the file would release the lock too; this just makes it explicit.
+        let _ = self.0.unlock();
+    }
+}
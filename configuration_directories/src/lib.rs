@@ -0,0 +1,3 @@
+//! The cache behind the `configuration_directories` binary.
+
+pub mod cache;
@@ -1,34 +1,146 @@
-use std::{
-    env,
-    path::{Path, PathBuf},
+use std::{env, error::Error, io, num::NonZeroUsize, process};
+
+use non_empty::{
+    config::{self, Layered},
+    env::EnvVar,
+    head,
+    path::{ExistingDir, PathError, PathList, WritableDir},
+    xdg, NonEmptyVec, Parse,
 };
 
-mod non_empty;
+use configuration_directories::cache::{Cache, CacheKey};
+use serde::Deserialize;
+
+const USAGE: &str =
+    "usage: configuration_directories (get <key> | put <key> <value> | evict <key> | config)";
+
+/// Read from every configuration directory; see [`config::load`].
+const CONFIG_FILE: &str = "configuration_directories.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Settings {
+    max_value_len: Option<NonZeroUsize>,
+}
+
+enum Command {
+    Get(CacheKey),
+    Put(CacheKey, String),
+    Evict(CacheKey),
+    Config,
+}
 
 fn main() {
-    let config_dirs = get_configuration_directories();
+    // Parse everything up front; past this point, only I/O can fail.
+    let (cache_dir, settings, command) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    };
 
-    match head(&config_dirs) {
-        Some(cache_dir) => initialize_cache(cache_dir),
-        None => panic!("should never happen; already checked configDirs is non-empty"),
+    if let Err(e) = run(&cache_dir, &settings, command) {
+        eprintln!("{e}");
+        process::exit(1);
     }
 }
 
-fn get_configuration_directories() -> Vec<PathBuf> {
-    let config_dirs_string = env::var("CONFIG_DIRS").unwrap_or_default();
-    let config_dirs_list: Vec<_> = config_dirs_string.split(',').map(|s| s.into()).collect();
+fn parse_args() -> Result<(WritableDir, Layered<Settings>, Command), Box<dyn Error>> {
+    let config_dirs = get_configuration_directories()?;
+    let settings = config::load(&config_dirs, CONFIG_FILE)?;
+    let cache_dir = WritableDir::parse(head(config_dirs))?;
+
+    let args: Vec<_> = env::args().skip(1).collect();
+    let command = parse_command(&args, &settings)?;
+
+    Ok((cache_dir, settings, command))
+}
+
+/// Uses `CONFIG_DIRS` if it is set, and the XDG base directories otherwise.
+fn get_configuration_directories() -> Result<NonEmptyVec<ExistingDir>, Box<dyn Error>> {
+    match EnvVar::<PathList>::new("CONFIG_DIRS").optional()? {
+        Some(config_dirs) => {
+            let (head, tail) = config_dirs.into_inner().into_parts();
 
-    if config_dirs_list.is_empty() {
-        panic!("CONFIG_DIRS cannot be empty")
+            let head = ExistingDir::parse(head)?;
+            let tail = tail
+                .into_iter()
+                .map(ExistingDir::parse)
+                .collect::<Result<_, _>>()?;
+
+            Ok(NonEmptyVec::new(head, tail))
+        }
+        None => {
+            // The XDG defaults need not exist, so skip any that don't.
+            let existing = xdg::config_dirs()
+                .into_vec()
+                .into_iter()
+                .filter_map(|dir| match ExistingDir::parse(dir) {
+                    Err(PathError::NotFound { .. }) => None,
+                    parsed => Some(parsed),
+                })
+                .collect::<Result<_, _>>()?;
+
+            NonEmptyVec::from_vec(existing)
+                .map_err(|_| "none of the XDG configuration directories exist".into())
+        }
     }
+}
+
+fn parse_command(args: &[String], settings: &Layered<Settings>) -> Result<Command, Box<dyn Error>> {
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["get", key] => Ok(Command::Get(CacheKey::parse_str(key)?)),
+        ["put", key, value] => {
+            let key = CacheKey::parse_str(key)?;
 
-    config_dirs_list
+            match (
+                settings.value().max_value_len,
+                settings.source("max_value_len"),
+            ) {
+                (Some(max), Some(source)) if value.len() > max.get() => Err(format!(
+                    "value is {} bytes, but max_value_len is {max} (set in {})",
+                    value.len(),
+                    source.display()
+                )
+                .into()),
+                _ => Ok(Command::Put(key, value.to_string())),
+            }
+        }
+        ["evict", key] => Ok(Command::Evict(CacheKey::parse_str(key)?)),
+        ["config"] => Ok(Command::Config),
+        _ => Err(USAGE.into()),
+    }
 }
 
-fn head<T>(slice: &[T]) -> Option<&T> {
-    slice.get(0)
+fn run(cache_dir: &WritableDir, settings: &Layered<Settings>, command: Command) -> io::Result<()> {
+    let cache = initialize_cache(cache_dir)?;
+
+    match command {
+        Command::Get(key) => match cache.get(&key)? {
+            Some(value) => println!("{}", String::from_utf8_lossy(&value)),
+            None => eprintln!("{} is not cached", key.get()),
+        },
+        Command::Put(key, value) => cache.put(&key, value.as_bytes())?,
+        Command::Evict(key) => {
+            if !cache.evict(&key)? {
+                eprintln!("{} is not cached", key.get());
+            }
+        }
+        Command::Config => {
+            println!("{:#?}", settings.value());
+
+            for (key, source) in settings.sources() {
+                println!("{key} is set in {}", source.display());
+            }
+        }
+    }
+
+    Ok(())
 }
 
-fn initialize_cache(cache_dir: &Path) {
-    todo!("just imagine this does something")
+fn initialize_cache(cache_dir: &WritableDir) -> io::Result<Cache> {
+    Cache::open(cache_dir)
 }
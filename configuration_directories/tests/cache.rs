@@ -0,0 +1,67 @@
+use std::{fs, io};
+
+use configuration_directories::cache::{Cache, CacheKey};
+use non_empty::{
+    path::{ExistingDir, WritableDir},
+    Parse,
+};
+use tempfile::TempDir;
+
+fn temp_dir() -> (TempDir, WritableDir) {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = WritableDir::parse(ExistingDir::parse(temp.path()).unwrap()).unwrap();
+
+    (temp, dir)
+}
+
+fn key(key: &str) -> CacheKey {
+    CacheKey::parse_str(key).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let (_temp, dir) = temp_dir();
+    let cache = Cache::open(&dir).unwrap();
+
+    assert_eq!(cache.get(&key("a")).unwrap(), None);
+
+    cache.put(&key("a"), b"value").unwrap();
+    assert_eq!(cache.get(&key("a")).unwrap(), Some(b"value".to_vec()));
+
+    assert!(cache.evict(&key("a")).unwrap());
+    assert!(!cache.evict(&key("a")).unwrap());
+    assert_eq!(cache.get(&key("a")).unwrap(), None);
+}
+
+#[test]
+fn overwrites_are_renamed_into_place() {
+    let (temp, dir) = temp_dir();
+    let cache = Cache::open(&dir).unwrap();
+
+    cache.put(&key("a"), b"old").unwrap();
+    cache.put(&key("a"), b"new").unwrap();
+
+    assert_eq!(cache.get(&key("a")).unwrap(), Some(b"new".to_vec()));
+
+    let tmp = temp.path().join("cache-v1").join("tmp");
+    assert_eq!(fs::read_dir(tmp).unwrap().count(), 0);
+}
+
+#[test]
+fn only_one_cache_is_open_at_a_time() {
+    let (_temp, dir) = temp_dir();
+    let cache = Cache::open(&dir).unwrap();
+
+    let error = Cache::open(&dir).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::ResourceBusy);
+
+    drop(cache);
+    assert!(Cache::open(&dir).is_ok());
+}
+
+#[test]
+fn keys_are_file_names() {
+    assert!(CacheKey::parse_str("config_v2-final").is_ok());
+    assert!(CacheKey::parse_str("../etc/passwd").is_err());
+    assert!(CacheKey::parse_str("").is_err());
+}
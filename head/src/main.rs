@@ -1,8 +1,15 @@
+use non_empty::{NonEmptySlice, NonEmptyVec};
+
 fn main() {
     let array: [u8; 0] = [];
     let h = head(&array);
 
     dbg!(h);
+
+    let vec = NonEmptyVec::singleton(0u8);
+    let h = total_head(&vec);
+
+    dbg!(h);
 }
 
 // Won't compile!
@@ -14,5 +21,10 @@ fn main() {
 
 // Will compile!
 fn head<T>(slice: &[T]) -> Option<&T> {
-    slice.get(0)
+    slice.first()
+}
+
+// Will compile, and never returns `None`!
+fn total_head<T>(slice: &NonEmptySlice<T>) -> &T {
+    slice.head()
 }
@@ -0,0 +1,113 @@
+//! Compares `NonEmptyVec`'s contiguous layout with the `(T, Vec<T>)` layout it replaced.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use non_empty::NonEmptyVec;
+
+/// The previous layout: the head stored apart from a possibly empty tail.
+#[derive(Clone)]
+struct SplitVec<T>(T, Vec<T>);
+
+impl<T: Ord> SplitVec<T> {
+    fn from_vec(mut tail: Vec<T>) -> Option<Self> {
+        if tail.is_empty() {
+            return None;
+        }
+
+        let head = tail.remove(0);
+        Some(Self(head, tail))
+    }
+
+    fn insert_front(&mut self, element: T) {
+        let old_head = std::mem::replace(&mut self.0, element);
+        self.1.insert(0, old_head);
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::once(&self.0).chain(&self.1)
+    }
+
+    /// A sorted copy, since the split layout cannot be sorted in place as one slice.
+    fn sorted(&self) -> Vec<&T> {
+        let mut sorted: Vec<_> = self.iter().collect();
+        sorted.sort();
+        sorted
+    }
+}
+
+const LEN: u64 = 10_000;
+
+fn input() -> Vec<u64> {
+    (0..LEN)
+        .map(|x| x.wrapping_mul(0x9E37_79B9_7F4A_7C15) % LEN)
+        .collect()
+}
+
+fn from_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_vec");
+
+    group.bench_function(BenchmarkId::new("contiguous", LEN), |b| {
+        b.iter_batched(input, NonEmptyVec::from_vec, BatchSize::SmallInput)
+    });
+    group.bench_function(BenchmarkId::new("split", LEN), |b| {
+        b.iter_batched(input, SplitVec::from_vec, BatchSize::SmallInput)
+    });
+}
+
+fn insert_front(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_front");
+    let contiguous = NonEmptyVec::from_vec(input()).unwrap();
+    let split = SplitVec::from_vec(input()).unwrap();
+
+    group.bench_function(BenchmarkId::new("contiguous", LEN), |b| {
+        b.iter_batched(
+            || contiguous.clone(),
+            |mut vec| vec.insert(0, black_box(0)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function(BenchmarkId::new("split", LEN), |b| {
+        b.iter_batched(
+            || split.clone(),
+            |mut vec| vec.insert_front(black_box(0)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    let contiguous = NonEmptyVec::from_vec(input()).unwrap();
+    let split = SplitVec::from_vec(input()).unwrap();
+
+    group.bench_function(BenchmarkId::new("contiguous", LEN), |b| {
+        b.iter(|| black_box(&contiguous[..]).iter().sum::<u64>())
+    });
+    group.bench_function(BenchmarkId::new("split", LEN), |b| {
+        b.iter(|| black_box(&split).iter().sum::<u64>())
+    });
+}
+
+fn binary_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort_then_binary_search");
+    let contiguous = NonEmptyVec::from_vec(input()).unwrap();
+    let split = SplitVec::from_vec(input()).unwrap();
+
+    group.bench_function(BenchmarkId::new("contiguous", LEN), |b| {
+        b.iter_batched(
+            || contiguous.clone(),
+            |mut vec| {
+                vec.sort();
+                vec.binary_search(&black_box(LEN / 2)).is_ok()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function(BenchmarkId::new("split", LEN), |b| {
+        b.iter(|| split.sorted().binary_search(&&black_box(LEN / 2)).is_ok())
+    });
+}
+
+criterion_group!(benches, from_vec, insert_front, sum, binary_search);
+criterion_main!(benches);
@@ -0,0 +1,322 @@
+use std::{
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut, Index, IndexMut},
+    slice::SliceIndex,
+};
+
+use crate::{iter::peek_non_empty, FullError, LengthError, NonEmptyIter, NonEmptySlice};
+
+/// A `Vec<T>` whose length is always between `MIN` and `MAX`, inclusive.
+///
+/// `MIN` must be at least one, so every `BoundedVec` is non-empty and has the
+/// total accessors of [`crate::NonEmptyVec`], which is the case with no
+/// maximum. Bounds which break `1 <= MIN <= MAX` fail to compile as soon as a
+/// value is constructed.
+///
+/// The elements are stored contiguously in an ordinary `Vec`, which is only
+/// reachable through methods that keep its length in bounds. It derefs to
+/// [`NonEmptySlice`], and through that to `[T]`, so slice methods such as
+/// `binary_search` and patterns like `[head, rest @ ..]` work as they do on a
+/// `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(Vec<T>);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    const VALID_BOUNDS: () = assert!(1 <= MIN && MIN <= MAX, "a BoundedVec needs 1 <= MIN <= MAX");
+
+    pub fn singleton(head: T) -> Self {
+        Self::from_array([head])
+    }
+
+    /// Builds a `BoundedVec` from an array, whose length is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// let empty: non_empty::NonEmptyVec<u8> = non_empty::NonEmptyVec::from_array([]);
+    /// ```
+    pub fn from_array<const N: usize>(array: [T; N]) -> Self {
+        const {
+            assert!(
+                MIN <= N && N <= MAX,
+                "the array's length is outside the BoundedVec's bounds"
+            )
+        };
+        Self::from_vec_unchecked(Vec::from(array))
+    }
+
+    /// Parses a `Vec<T>` without copying its elements.
+    pub fn from_vec(vec: Vec<T>) -> Result<Self, LengthError<Vec<T>>> {
+        let () = Self::VALID_BOUNDS;
+
+        if (MIN..=MAX).contains(&vec.len()) {
+            Ok(Self(vec))
+        } else {
+            let actual = vec.len();
+            Err(LengthError::new(vec, actual, MIN..=MAX))
+        }
+    }
+
+    /// The caller must ensure `vec` is within bounds.
+    pub(crate) fn from_vec_unchecked(vec: Vec<T>) -> Self {
+        let () = Self::VALID_BOUNDS;
+        debug_assert!((MIN..=MAX).contains(&vec.len()));
+
+        Self(vec)
+    }
+
+    /// Parses any iterator, preserving the order of its elements.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, LengthError<Vec<T>>> {
+        Self::try_from_iter(iter)
+    }
+
+    /// Like [`BoundedVec::from_iter`], but without collecting into a `Vec` first.
+    ///
+    /// The head is taken lazily, so an empty iterator fails without allocating.
+    /// Reading stops one item past `MAX`, so an iterator which is too long, or
+    /// even endless, fails with just the first `MAX + 1` items.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, LengthError<Vec<T>>> {
+        match peek_non_empty(iter) {
+            Some((head, mut tail)) => {
+                let mut vec = Vec::with_capacity(tail.size_hint().0.saturating_add(1).min(MAX));
+                vec.push(head);
+                vec.extend(tail.by_ref().take(MAX - 1));
+                vec.extend(tail.next());
+
+                Self::from_vec(vec)
+            }
+            None => Self::from_vec(Vec::new()),
+        }
+    }
+
+    pub fn head(&self) -> &T {
+        self.first()
+    }
+
+    pub fn tail(&self) -> &[T] {
+        &self.0[1..]
+    }
+
+    pub fn as_slice(&self) -> &NonEmptySlice<T> {
+        NonEmptySlice::from_slice_unchecked(&self.0)
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut NonEmptySlice<T> {
+        NonEmptySlice::from_mut_slice_unchecked(&mut self.0)
+    }
+
+    pub fn into_head(self) -> T {
+        let mut vec = self.0;
+        vec.swap_remove(0)
+    }
+
+    /// Splits off the head, which always exists.
+    pub fn into_parts(self) -> (T, Vec<T>) {
+        let mut tail = self.0;
+        let head = tail.remove(0);
+
+        (head, tail)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    pub fn first(&self) -> &T {
+        self.0.first().expect(NON_EMPTY)
+    }
+
+    pub fn first_mut(&mut self) -> &mut T {
+        self.0.first_mut().expect(NON_EMPTY)
+    }
+
+    pub fn last(&self) -> &T {
+        self.0.last().expect(NON_EMPTY)
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        self.0.last_mut().expect(NON_EMPTY)
+    }
+
+    pub fn iter(&self) -> NonEmptyIter<std::slice::Iter<'_, T>> {
+        NonEmptyIter::new(self.0.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> NonEmptyIter<std::slice::IterMut<'_, T>> {
+        NonEmptyIter::new(self.0.iter_mut())
+    }
+
+    /// Consumes the vec into a [`crate::NonEmptyIterator`].
+    ///
+    /// This shadows [`IntoIterator::into_iter`], which `for` loops still use.
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> NonEmptyIter<std::vec::IntoIter<T>> {
+        NonEmptyIter::new(self.0.into_iter())
+    }
+
+    /// Appends an element, unless the vec already has `MAX` elements.
+    pub fn try_push(&mut self, value: T) -> Result<(), FullError<T>> {
+        if self.0.len() == MAX {
+            return Err(FullError::new(value));
+        }
+
+        self.0.push(value);
+        Ok(())
+    }
+
+    /// Inserts an element at `index`, unless the vec already has `MAX` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, like [`Vec::insert`].
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), FullError<T>> {
+        if self.0.len() == MAX {
+            return Err(FullError::new(element));
+        }
+
+        self.0.insert(index, element);
+        Ok(())
+    }
+
+    /// Removes the last element, unless that would leave fewer than `MIN`.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.len() > MIN {
+            self.0.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Shortens the vec to `len` elements, but never below `MIN`.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len.max(MIN))
+    }
+}
+
+/// Deduplicating can leave a single element, so it needs `MIN == 1`.
+impl<T, const MAX: usize> BoundedVec<T, 1, MAX> {
+    /// Removes consecutive repeated elements, like [`Vec::dedup`].
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.0.dedup()
+    }
+
+    pub fn dedup_by_key<K: PartialEq>(&mut self, key: impl FnMut(&mut T) -> K) {
+        self.0.dedup_by_key(key)
+    }
+
+    pub fn dedup_by(&mut self, same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        self.0.dedup_by(same_bucket)
+    }
+}
+
+const NON_EMPTY: &str = "a BoundedVec is never empty";
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = NonEmptySlice<T>;
+
+    fn deref(&self) -> &NonEmptySlice<T> {
+        self.as_slice()
+    }
+}
+
+/// Only the elements can be changed through a `&mut NonEmptySlice`, never the length.
+impl<T, const MIN: usize, const MAX: usize> DerefMut for BoundedVec<T, MIN, MAX> {
+    fn deref_mut(&mut self) -> &mut NonEmptySlice<T> {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for BoundedVec<T, MIN, MAX> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, I, const MIN: usize, const MAX: usize> Index<I> for BoundedVec<T, MIN, MAX>
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, I, const MIN: usize, const MAX: usize> IndexMut<I> for BoundedVec<T, MIN, MAX>
+where
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Only a vec without a maximum can grow infallibly.
+impl<T, const MIN: usize> BoundedVec<T, MIN, { usize::MAX }> {
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Inserts an element at `index`, shifting everything after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, like [`Vec::insert`].
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.0.insert(index, element)
+    }
+}
+
+impl<T, const MIN: usize> Extend<T> for BoundedVec<T, MIN, { usize::MAX }> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for BoundedVec<T, MIN, MAX> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a BoundedVec<T, MIN, MAX> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a mut BoundedVec<T, MIN, MAX> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    type Error = LengthError<Vec<T>>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::from_vec(vec)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<BoundedVec<T, MIN, MAX>> for Vec<T> {
+    fn from(vec: BoundedVec<T, MIN, MAX>) -> Self {
+        vec.into_vec()
+    }
+}
@@ -0,0 +1,196 @@
+//! Loading one configuration file layered across several directories.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{NonEmptyIterator, NonEmptySlice};
+
+/// A parsed configuration, and the file each of its values came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layered<T> {
+    value: T,
+    sources: BTreeMap<String, PathBuf>,
+}
+
+impl<T> Layered<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// The file which set `key`, written as a dotted path such as `cache.max_len`.
+    ///
+    /// Arrays are replaced wholesale when merging, so they have a single source.
+    pub fn source(&self, key: &str) -> Option<&Path> {
+        self.sources.get(key).map(PathBuf::as_path)
+    }
+
+    /// Every key which was set by a file, in order, with that file.
+    pub fn sources(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.sources
+            .iter()
+            .map(|(key, path)| (key.as_str(), path.as_path()))
+    }
+}
+
+/// Loads `file_name` from each of `dirs` and parses the merged result.
+///
+/// `dirs` is in priority order, as returned by e.g. [`crate::xdg::config_dirs`],
+/// so files are merged starting from the last directory: a value in an
+/// earlier directory overrides the same value in a later one. Tables are
+/// merged key by key, and every other value is replaced.
+///
+/// The format is chosen by extension: `.toml` or `.json`. Directories without
+/// the file are skipped, and if no directory has it, `T` is parsed from an
+/// empty table.
+pub fn load<T, P>(dirs: &NonEmptySlice<P>, file_name: &str) -> Result<Layered<T>, ConfigError>
+where
+    T: DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let format = Format::from_file_name(file_name)?;
+
+    let mut merged = Map::new();
+    let mut sources = BTreeMap::new();
+
+    for dir in dirs.iter().rev() {
+        let path = dir.as_ref().join(file_name);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(source) => return Err(ConfigError::Io { path, source }),
+        };
+
+        let layer = match format.parse(&contents) {
+            Ok(Value::Object(layer)) => layer,
+            Ok(_) => {
+                return Err(ConfigError::Syntax {
+                    path,
+                    message: "expected a table at the top level".to_string(),
+                })
+            }
+            Err(message) => return Err(ConfigError::Syntax { path, message }),
+        };
+
+        merge(&mut merged, layer, "", &path, &mut sources);
+    }
+
+    let value = serde_json::from_value(Value::Object(merged)).map_err(ConfigError::Invalid)?;
+
+    Ok(Layered { value, sources })
+}
+
+fn merge(
+    into: &mut Map<String, Value>,
+    layer: Map<String, Value>,
+    prefix: &str,
+    path: &Path,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    for (key, value) in layer {
+        let dotted = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+
+        match (into.get_mut(&key), value) {
+            (Some(Value::Object(existing)), Value::Object(table)) => {
+                merge(existing, table, &dotted, path, sources);
+            }
+            (_, value) => {
+                // Forget where the replaced value (and anything under it) came from.
+                let nested = format!("{dotted}.");
+                sources.retain(|key, _| key != &dotted && !key.starts_with(&nested));
+
+                record_sources(&value, &dotted, path, sources);
+                into.insert(key, value);
+            }
+        }
+    }
+}
+
+fn record_sources(
+    value: &Value,
+    dotted: &str,
+    path: &Path,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    match value {
+        Value::Object(table) => {
+            for (key, value) in table {
+                record_sources(value, &format!("{dotted}.{key}"), path, sources);
+            }
+        }
+        _ => {
+            sources.insert(dotted.to_string(), path.to_path_buf());
+        }
+    }
+}
+
+enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    fn from_file_name(file_name: &str) -> Result<Self, ConfigError> {
+        match Path::new(file_name).extension().and_then(|e| e.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            _ => Err(ConfigError::UnknownFormat {
+                file_name: file_name.to_string(),
+            }),
+        }
+    }
+
+    fn parse(&self, contents: &str) -> Result<Value, String> {
+        match self {
+            Self::Toml => toml::from_str(contents).map_err(|e| e.to_string()),
+            Self::Json => serde_json::from_str(contents).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Why a layered configuration could not be loaded.
+#[derive(Debug)]
+pub enum ConfigError {
+    UnknownFormat { file_name: String },
+    Io { path: PathBuf, source: io::Error },
+    Syntax { path: PathBuf, message: String },
+    Invalid(serde_json::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat { file_name } => {
+                write!(f, "{file_name} is neither a .toml nor a .json file")
+            }
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::Syntax { path, message } => write!(f, "{}: {message}", path.display()),
+            Self::Invalid(e) => write!(f, "invalid configuration: {e}"),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Invalid(e) => Some(e),
+            Self::UnknownFormat { .. } | Self::Syntax { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Parsing environment variables into typed values.
+
+use std::{env, error::Error, ffi::OsString, fmt, marker::PhantomData};
+
+use crate::{peek_non_empty, NonEmptyVec, Parse, ParseError};
+
+/// Why an environment variable could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    Unset {
+        name: String,
+    },
+    NotUnicode {
+        name: String,
+        value: OsString,
+    },
+    Empty {
+        name: String,
+    },
+    Invalid {
+        name: String,
+        error: ParseError,
+    },
+    InvalidElement {
+        name: String,
+        index: usize,
+        error: ParseError,
+    },
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unset { name } => write!(f, "{name} must be set"),
+            Self::NotUnicode { name, value } => {
+                write!(f, "{name} is not valid unicode: {value:?}")
+            }
+            Self::Empty { name } => write!(f, "{name} cannot be empty"),
+            Self::Invalid { name, error } => write!(f, "{name} is invalid: {error}"),
+            Self::InvalidElement { name, index, error } => {
+                write!(f, "element {index} of {name} is invalid: {error}")
+            }
+        }
+    }
+}
+
+impl Error for EnvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } | Self::InvalidElement { error, .. } => Some(error),
+            Self::Unset { .. } | Self::NotUnicode { .. } | Self::Empty { .. } => None,
+        }
+    }
+}
+
+/// A single environment variable parsed as a `T`.
+///
+/// ```no_run
+/// use non_empty::env::EnvVar;
+///
+/// let port = EnvVar::<u16>::new("PORT").optional()?.unwrap_or(8080);
+/// # Ok::<(), non_empty::env::EnvError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvVar<T> {
+    name: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Parse> EnvVar<T> {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Treats the variable as a list of `T`s separated by `separator`.
+    pub fn with_separator(self, separator: char) -> EnvList<T> {
+        EnvList {
+            var: self,
+            separator,
+        }
+    }
+
+    pub fn required(&self) -> Result<T, EnvError> {
+        let value = self.read()?.ok_or_else(|| EnvError::Unset {
+            name: self.name.clone(),
+        })?;
+
+        T::parse_str(&value).map_err(|error| EnvError::Invalid {
+            name: self.name.clone(),
+            error,
+        })
+    }
+
+    /// Like [`EnvVar::required`], but an unset variable is `None` rather than an error.
+    pub fn optional(&self) -> Result<Option<T>, EnvError> {
+        match self.required() {
+            Ok(value) => Ok(Some(value)),
+            Err(EnvError::Unset { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Reads the raw value; a variable which is set but empty is an error.
+    fn read(&self) -> Result<Option<String>, EnvError> {
+        let Some(value) = env::var_os(&self.name) else {
+            return Ok(None);
+        };
+
+        let value = value.into_string().map_err(|value| EnvError::NotUnicode {
+            name: self.name.clone(),
+            value,
+        })?;
+
+        if value.is_empty() {
+            return Err(EnvError::Empty {
+                name: self.name.clone(),
+            });
+        }
+
+        Ok(Some(value))
+    }
+}
+
+/// An environment variable holding a separated list of at least one `T`.
+///
+/// ```no_run
+/// use std::path::PathBuf;
+///
+/// use non_empty::env::EnvVar;
+///
+/// let config_dirs = EnvVar::<PathBuf>::new("CONFIG_DIRS")
+///     .with_separator(',')
+///     .required()?;
+/// # Ok::<(), non_empty::env::EnvError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvList<T> {
+    var: EnvVar<T>,
+    separator: char,
+}
+
+impl<T: Parse> EnvList<T> {
+    pub fn required(&self) -> Result<NonEmptyVec<T>, EnvError> {
+        let name = &self.var.name;
+        let value = self
+            .var
+            .read()?
+            .ok_or_else(|| EnvError::Unset { name: name.clone() })?;
+
+        let elements = value
+            .split(self.separator)
+            .enumerate()
+            .map(|(index, element)| {
+                T::parse_str(element).map_err(|error| EnvError::InvalidElement {
+                    name: name.clone(),
+                    index,
+                    error,
+                })
+            });
+
+        let (head, tail) =
+            peek_non_empty(elements).ok_or_else(|| EnvError::Empty { name: name.clone() })?;
+
+        Ok(NonEmptyVec::new(head?, tail.collect::<Result<_, _>>()?))
+    }
+
+    /// Like [`EnvList::required`], but an unset variable is `None` rather than an error.
+    pub fn optional(&self) -> Result<Option<NonEmptyVec<T>>, EnvError> {
+        match self.required() {
+            Ok(value) => Ok(Some(value)),
+            Err(EnvError::Unset { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
+
+/// The input to a non-empty parser was empty.
+///
+/// The original input is kept, so a failed parse never loses data (or, for a
+/// `Vec`, its allocation).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyError<I>(I);
+
+impl<I> EmptyError<I> {
+    pub(crate) fn new(input: I) -> Self {
+        Self(input)
+    }
+
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I> fmt::Display for EmptyError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected at least one element")
+    }
+}
+
+impl<I: fmt::Debug> Error for EmptyError<I> {}
+
+/// The input to a non-blank parser was empty or only whitespace.
+///
+/// Like [`EmptyError`], the original input is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlankError<I>(I);
+
+impl<I> BlankError<I> {
+    pub(crate) fn new(input: I) -> Self {
+        Self(input)
+    }
+
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I> fmt::Display for BlankError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected at least one non-whitespace character")
+    }
+}
+
+impl<I: fmt::Debug> Error for BlankError<I> {}
+
+/// The input to a length-bounded parser had too few or too many elements.
+///
+/// Like [`EmptyError`], the original input is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthError<I> {
+    input: I,
+    actual: usize,
+    expected: RangeInclusive<usize>,
+}
+
+impl<I> LengthError<I> {
+    pub(crate) fn new(input: I, actual: usize, expected: RangeInclusive<usize>) -> Self {
+        Self {
+            input,
+            actual,
+            expected,
+        }
+    }
+
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+
+    /// The allowed lengths, where `usize::MAX` as the end means there is no maximum.
+    pub fn expected(&self) -> RangeInclusive<usize> {
+        self.expected.clone()
+    }
+
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I> fmt::Display for LengthError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = Bounds(*self.expected.start(), *self.expected.end());
+        write!(f, "expected {bounds}, found {}", self.actual)
+    }
+}
+
+impl<I: fmt::Debug> Error for LengthError<I> {}
+
+/// Describes a range of lengths in words, e.g. "between 1 and 8 elements".
+pub(crate) struct Bounds(pub(crate) usize, pub(crate) usize);
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+
+        match *self {
+            Self(1, usize::MAX) => f.write_str("at least one element"),
+            Self(min, usize::MAX) => write!(f, "at least {min} element{}", plural(min)),
+            Self(min, max) if min == max => write!(f, "exactly {min} element{}", plural(min)),
+            Self(min, max) => write!(f, "between {min} and {max} elements"),
+        }
+    }
+}
+
+/// Adding an element would have taken a bounded collection past its maximum length.
+///
+/// The rejected element is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FullError<T>(T);
+
+impl<T> FullError<T> {
+    pub(crate) fn new(element: T) -> Self {
+        Self(element)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Display for FullError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot add an element beyond the maximum length")
+    }
+}
+
+impl<T: fmt::Debug> Error for FullError<T> {}
+
+/// Why a value could not be parsed into a more precise type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    Duplicate { key: String },
+    OutOfRange { value: String, range: String },
+    Malformed { input: String, reason: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("expected at least one element"),
+            Self::Duplicate { key } => write!(f, "duplicate key `{key}`"),
+            Self::OutOfRange { value, range } => write!(f, "`{value}` is out of range {range}"),
+            Self::Malformed { input, reason } => write!(f, "malformed input `{input}`: {reason}"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl<I> From<EmptyError<I>> for ParseError {
+    fn from(_: EmptyError<I>) -> Self {
+        Self::Empty
+    }
+}
+
+/// An empty input is [`ParseError::Empty`], and any other length is out of range.
+impl<I> From<LengthError<I>> for ParseError {
+    fn from(error: LengthError<I>) -> Self {
+        let (min, max) = error.expected.into_inner();
+
+        if error.actual == 0 {
+            return Self::Empty;
+        }
+
+        Self::OutOfRange {
+            value: format!("length {}", error.actual),
+            range: if max == usize::MAX {
+                format!("{min}..")
+            } else {
+                format!("{min}..={max}")
+            },
+        }
+    }
+}
+
+impl<I: AsRef<str>> From<BlankError<I>> for ParseError {
+    fn from(error: BlankError<I>) -> Self {
+        Self::Malformed {
+            input: error.0.as_ref().to_string(),
+            reason: error.to_string(),
+        }
+    }
+}
+
+/// Removing an element would have left a non-empty collection empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WouldBeEmptyError;
+
+impl fmt::Display for WouldBeEmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot remove the only element")
+    }
+}
+
+impl Error for WouldBeEmptyError {}
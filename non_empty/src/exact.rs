@@ -0,0 +1,40 @@
+use crate::LengthError;
+
+/// Parses a `Vec<T>` of exactly `N` elements into an array.
+///
+/// This is a trait rather than a free function so that the element type is
+/// inferred, leaving `N` as the only generic to write:
+///
+/// ```
+/// use non_empty::ParseExact;
+///
+/// let [r, g, b] = vec![255u8, 128, 0].parse_exact::<3>().unwrap();
+/// assert_eq!((r, g, b), (255, 128, 0));
+/// ```
+pub trait ParseExact<T> {
+    fn parse_exact<const N: usize>(self) -> Result<[T; N], LengthError<Vec<T>>>;
+}
+
+impl<T> ParseExact<T> for Vec<T> {
+    fn parse_exact<const N: usize>(self) -> Result<[T; N], LengthError<Vec<T>>> {
+        let actual = self.len();
+        <[T; N]>::try_from(self).map_err(|vec| LengthError::new(vec, actual, N..=N))
+    }
+}
+
+/// Splits `input` on `separator` into exactly `N` parts, e.g. a `host:port` pair.
+///
+/// ```
+/// let [host, port] = non_empty::split_exact::<2>("localhost:8080", ':').unwrap();
+/// assert_eq!((host, port), ("localhost", "8080"));
+/// ```
+pub fn split_exact<const N: usize>(
+    input: &str,
+    separator: char,
+) -> Result<[&str; N], LengthError<&str>> {
+    input
+        .split(separator)
+        .collect::<Vec<_>>()
+        .parse_exact()
+        .map_err(|error| LengthError::new(input, error.actual(), N..=N))
+}
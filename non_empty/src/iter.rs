@@ -0,0 +1,155 @@
+use std::iter::{Chain, Cloned, Copied, Enumerate, Map, Rev, Zip};
+
+use crate::NonEmptyVec;
+
+/// An iterator which is known to yield at least one item.
+///
+/// Adapters which cannot remove items keep that guarantee, so terminal
+/// operations like [`NonEmptyIterator::max`] and [`NonEmptyIterator::collect`]
+/// are total. This is deliberately not a subtrait of [`Iterator`], whose
+/// methods of the same names would be ambiguous; call `into_iter` to get an
+/// ordinary iterator, e.g. to `filter`.
+///
+/// The trait is sealed: [`NonEmptyIter`] is its only implementation, so no
+/// other crate can claim an empty iterator is non-empty:
+///
+/// ```compile_fail
+/// struct Nothing;
+///
+/// impl IntoIterator for Nothing {
+///     type Item = u8;
+///     type IntoIter = std::iter::Empty<u8>;
+///
+///     fn into_iter(self) -> Self::IntoIter {
+///         std::iter::empty()
+///     }
+/// }
+///
+/// impl non_empty::NonEmptyIterator for Nothing {}
+/// ```
+pub trait NonEmptyIterator: IntoIterator + Sized + sealed::Sealed {
+    fn first(self) -> Self::Item {
+        self.into_iter().next().expect(NON_EMPTY)
+    }
+
+    fn last(self) -> Self::Item {
+        self.into_iter().last().expect(NON_EMPTY)
+    }
+
+    /// Returns the last maximum item, like [`Iterator::max`].
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.into_iter().max().expect(NON_EMPTY)
+    }
+
+    /// Returns the first minimum item, like [`Iterator::min`].
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.into_iter().min().expect(NON_EMPTY)
+    }
+
+    /// Like [`Iterator::reduce`], but never returns `None`.
+    fn reduce(self, f: impl FnMut(Self::Item, Self::Item) -> Self::Item) -> Self::Item {
+        self.into_iter().reduce(f).expect(NON_EMPTY)
+    }
+
+    fn collect(self) -> NonEmptyVec<Self::Item> {
+        let vec: Vec<_> = self.into_iter().collect();
+        NonEmptyVec::from_vec_unchecked(vec)
+    }
+
+    fn map<B, F>(self, f: F) -> NonEmptyIter<Map<Self::IntoIter, F>>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        NonEmptyIter(self.into_iter().map(f))
+    }
+
+    fn copied<'a, T>(self) -> NonEmptyIter<Copied<Self::IntoIter>>
+    where
+        T: Copy + 'a,
+        Self: IntoIterator<Item = &'a T>,
+    {
+        NonEmptyIter(self.into_iter().copied())
+    }
+
+    fn cloned<'a, T>(self) -> NonEmptyIter<Cloned<Self::IntoIter>>
+    where
+        T: Clone + 'a,
+        Self: IntoIterator<Item = &'a T>,
+    {
+        NonEmptyIter(self.into_iter().cloned())
+    }
+
+    /// Zipping stops at the shorter iterator, so both must be non-empty.
+    fn zip<U: NonEmptyIterator>(self, other: U) -> NonEmptyIter<Zip<Self::IntoIter, U::IntoIter>> {
+        NonEmptyIter(self.into_iter().zip(other))
+    }
+
+    fn enumerate(self) -> NonEmptyIter<Enumerate<Self::IntoIter>> {
+        NonEmptyIter(self.into_iter().enumerate())
+    }
+
+    /// Only `self` needs to be non-empty; `other` may yield nothing.
+    fn chain<U>(self, other: U) -> NonEmptyIter<Chain<Self::IntoIter, U::IntoIter>>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        NonEmptyIter(self.into_iter().chain(other))
+    }
+
+    fn rev(self) -> NonEmptyIter<Rev<Self::IntoIter>>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        NonEmptyIter(self.into_iter().rev())
+    }
+}
+
+/// Takes the first item off `iter`, or returns `None` if it is empty.
+///
+/// Nothing past the first item is consumed, so the rest can still be handled
+/// lazily.
+pub fn peek_non_empty<I: IntoIterator>(iter: I) -> Option<(I::Item, I::IntoIter)> {
+    let mut iter = iter.into_iter();
+    let head = iter.next()?;
+
+    Some((head, iter))
+}
+
+const NON_EMPTY: &str = "a NonEmptyIterator yields at least one item";
+
+/// An iterator adapter which yields at least one item.
+///
+/// Returned by [`NonEmptyVec::iter`] and the adapters on [`NonEmptyIterator`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NonEmptyIter<I>(I);
+
+impl<I: Iterator> NonEmptyIter<I> {
+    /// The caller must ensure `iter` yields at least one item.
+    pub(crate) fn new(iter: I) -> Self {
+        Self(iter)
+    }
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyIter<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+
+    fn into_iter(self) -> I {
+        self.0
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyIter<I> {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<I: Iterator> Sealed for super::NonEmptyIter<I> {}
+}
@@ -0,0 +1,55 @@
+//! Collections which are proven, in the type system, to contain at least one element.
+
+#[macro_use]
+mod macros;
+
+mod bounded;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod env;
+mod error;
+mod exact;
+mod iter;
+mod map;
+mod ops;
+mod parse;
+pub mod path;
+pub mod refined;
+#[cfg(feature = "serde")]
+mod serde;
+mod set;
+mod slice;
+mod string;
+mod unique;
+mod validated;
+mod vec;
+pub mod xdg;
+
+pub use bounded::BoundedVec;
+pub use error::{BlankError, EmptyError, FullError, LengthError, ParseError, WouldBeEmptyError};
+pub use exact::{split_exact, ParseExact};
+pub use iter::{peek_non_empty, NonEmptyIter, NonEmptyIterator};
+pub use map::{
+    parse_non_empty_btree_map, parse_non_empty_hash_map, NonEmptyBTreeMap, NonEmptyHashMap,
+};
+pub use ops::NonEmptyOps;
+pub use parse::Parse;
+pub use set::{parse_non_empty_hash_set, NonEmptyHashSet};
+pub use slice::NonEmptySlice;
+pub use string::{NonBlankStr, NonBlankString, NonEmptyString};
+pub use unique::{
+    parse_unique_keys, parse_unique_keys_ordered, DuplicateKeysError, UniqueKeyBTreeMap,
+    UniqueKeyMap,
+};
+pub use validated::Validated;
+pub use vec::{head, parse_non_empty, validate_non_empty, NonEmptyVec};
+
+#[cfg(feature = "derive")]
+pub use non_empty_derive::Parse;
+
+// Used by code generated by `#[derive(Parse)]`; not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "regex")]
+    pub use regex;
+}
@@ -0,0 +1,25 @@
+/// Implements `PartialEq` and `Eq` for a newtype around a `HashMap` or
+/// `HashSet`.
+///
+/// These are implemented by hand, since comparing hashed collections needs
+/// `Eq + Hash` keys, which are stricter bounds than deriving adds.
+macro_rules! impl_hashed_eq {
+    ($ty:ident<K, V>) => {
+        impl<K: Eq + ::std::hash::Hash, V: PartialEq> PartialEq for $ty<K, V> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<K: Eq + ::std::hash::Hash, V: Eq> Eq for $ty<K, V> {}
+    };
+    ($ty:ident<T>) => {
+        impl<T: Eq + ::std::hash::Hash> PartialEq for $ty<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T: Eq + ::std::hash::Hash> Eq for $ty<T> {}
+    };
+}
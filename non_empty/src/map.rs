@@ -0,0 +1,228 @@
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    num::NonZeroUsize,
+    ops::Deref,
+};
+
+use crate::{EmptyError, WouldBeEmptyError};
+
+/// A `HashMap` which always contains at least one entry.
+///
+/// Dereferences to the inner `HashMap` for reading; every method which could
+/// remove entries is checked.
+#[derive(Debug, Clone)]
+pub struct NonEmptyHashMap<K, V>(HashMap<K, V>);
+
+impl<K: Eq + Hash, V> NonEmptyHashMap<K, V> {
+    pub fn singleton(key: K, value: V) -> Self {
+        Self(HashMap::from([(key, value)]))
+    }
+
+    pub fn from_map(map: HashMap<K, V>) -> Result<Self, EmptyError<HashMap<K, V>>> {
+        if map.is_empty() {
+            Err(EmptyError::new(map))
+        } else {
+            Ok(Self(map))
+        }
+    }
+
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    /// The first entry in iteration order, which is arbitrary for a `HashMap`.
+    pub fn first_key_value(&self) -> (&K, &V) {
+        self.0.iter().next().expect(NON_EMPTY)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes `key`, unless it is the only entry.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, WouldBeEmptyError>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains_key(key) {
+            return Err(WouldBeEmptyError);
+        }
+
+        Ok(self.0.remove(key))
+    }
+
+    pub fn into_inner(self) -> HashMap<K, V> {
+        self.0
+    }
+}
+
+/// A `BTreeMap` which always contains at least one entry.
+///
+/// Dereferences to the inner `BTreeMap` for reading; every method which could
+/// remove entries is checked.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBTreeMap<K, V>(BTreeMap<K, V>);
+
+impl<K: Ord, V> NonEmptyBTreeMap<K, V> {
+    pub fn singleton(key: K, value: V) -> Self {
+        Self(BTreeMap::from([(key, value)]))
+    }
+
+    pub fn from_map(map: BTreeMap<K, V>) -> Result<Self, EmptyError<BTreeMap<K, V>>> {
+        if map.is_empty() {
+            Err(EmptyError::new(map))
+        } else {
+            Ok(Self(map))
+        }
+    }
+
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    /// The entry with the smallest key.
+    pub fn first_key_value(&self) -> (&K, &V) {
+        self.0.first_key_value().expect(NON_EMPTY)
+    }
+
+    /// The entry with the largest key.
+    pub fn last_key_value(&self) -> (&K, &V) {
+        self.0.last_key_value().expect(NON_EMPTY)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes `key`, unless it is the only entry.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, WouldBeEmptyError>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains_key(key) {
+            return Err(WouldBeEmptyError);
+        }
+
+        Ok(self.0.remove(key))
+    }
+
+    pub fn into_inner(self) -> BTreeMap<K, V> {
+        self.0
+    }
+}
+
+const NON_EMPTY: &str = "a NonEmptyHashMap or NonEmptyBTreeMap is never empty";
+
+impl_hashed_eq!(NonEmptyHashMap<K, V>);
+
+impl<K, V> Deref for NonEmptyHashMap<K, V> {
+    type Target = HashMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> Deref for NonEmptyBTreeMap<K, V> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K: Eq + Hash, V> TryFrom<HashMap<K, V>> for NonEmptyHashMap<K, V> {
+    type Error = EmptyError<HashMap<K, V>>;
+
+    fn try_from(map: HashMap<K, V>) -> Result<Self, Self::Error> {
+        Self::from_map(map)
+    }
+}
+
+impl<K: Ord, V> TryFrom<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+    type Error = EmptyError<BTreeMap<K, V>>;
+
+    fn try_from(map: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        Self::from_map(map)
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for NonEmptyHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for NonEmptyBTreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K, V> IntoIterator for NonEmptyHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V> IntoIterator for NonEmptyBTreeMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a NonEmptyHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::collections::hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a NonEmptyBTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::collections::btree_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+pub fn parse_non_empty_hash_map<K: Eq + Hash, V>(
+    map: HashMap<K, V>,
+) -> Result<NonEmptyHashMap<K, V>, EmptyError<HashMap<K, V>>> {
+    NonEmptyHashMap::from_map(map)
+}
+
+pub fn parse_non_empty_btree_map<K: Ord, V>(
+    map: BTreeMap<K, V>,
+) -> Result<NonEmptyBTreeMap<K, V>, EmptyError<BTreeMap<K, V>>> {
+    NonEmptyBTreeMap::from_map(map)
+}
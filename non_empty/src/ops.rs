@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use crate::{BoundedVec, NonEmptySlice};
+
+/// Operations which are partial on ordinary collections, but total on non-empty ones.
+///
+/// These mirror Haskell's `Data.List.NonEmpty`: where `iter().max()` returns
+/// an `Option`, [`NonEmptyOps::maximum`] returns the element itself.
+pub trait NonEmptyOps<T> {
+    /// The one required method; every other operation is derived from it.
+    fn split_first(&self) -> (&T, &[T]);
+
+    fn head(&self) -> &T {
+        self.split_first().0
+    }
+
+    fn last(&self) -> &T {
+        let (head, tail) = self.split_first();
+        tail.last().unwrap_or(head)
+    }
+
+    /// Returns the last maximum element, like [`Iterator::max`].
+    fn maximum(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.maximum_by(T::cmp)
+    }
+
+    /// Returns the first minimum element, like [`Iterator::min`].
+    fn minimum(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.minimum_by(T::cmp)
+    }
+
+    fn maximum_by(&self, mut compare: impl FnMut(&T, &T) -> Ordering) -> &T {
+        let (head, tail) = self.split_first();
+
+        tail.iter().fold(head, |max, x| match compare(x, max) {
+            Ordering::Less => max,
+            Ordering::Equal | Ordering::Greater => x,
+        })
+    }
+
+    fn minimum_by(&self, mut compare: impl FnMut(&T, &T) -> Ordering) -> &T {
+        let (head, tail) = self.split_first();
+
+        tail.iter().fold(head, |min, x| match compare(x, min) {
+            Ordering::Less => x,
+            Ordering::Equal | Ordering::Greater => min,
+        })
+    }
+
+    fn max_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> &T {
+        let (head, tail) = self.split_first();
+
+        let (_, max) = tail.iter().fold((f(head), head), |(max_key, max), x| {
+            let key = f(x);
+
+            match key.cmp(&max_key) {
+                Ordering::Less => (max_key, max),
+                Ordering::Equal | Ordering::Greater => (key, x),
+            }
+        });
+
+        max
+    }
+
+    fn min_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> &T {
+        let (head, tail) = self.split_first();
+
+        let (_, min) = tail.iter().fold((f(head), head), |(min_key, min), x| {
+            let key = f(x);
+
+            match key.cmp(&min_key) {
+                Ordering::Less => (key, x),
+                Ordering::Equal | Ordering::Greater => (min_key, min),
+            }
+        });
+
+        min
+    }
+
+    /// Folds every element into an accumulator seeded from the head, so no
+    /// initial value is needed.
+    fn fold1<B>(&self, init: impl FnOnce(&T) -> B, f: impl FnMut(B, &T) -> B) -> B {
+        let (head, tail) = self.split_first();
+        tail.iter().fold(init(head), f)
+    }
+
+    /// Like [`Iterator::reduce`], but never returns `None`.
+    fn reduce(&self, f: impl FnMut(T, &T) -> T) -> T
+    where
+        T: Clone,
+    {
+        self.fold1(T::clone, f)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> NonEmptyOps<T> for BoundedVec<T, MIN, MAX> {
+    fn split_first(&self) -> (&T, &[T]) {
+        (self.head(), self.tail())
+    }
+}
+
+impl<T> NonEmptyOps<T> for NonEmptySlice<T> {
+    fn split_first(&self) -> (&T, &[T]) {
+        NonEmptySlice::split_first(self)
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// Types which can be parsed from text, such as an environment variable.
+///
+/// Types using `#[derive(Parse)]` implement this whenever their inner type
+/// does, running their own checks after the inner type is parsed.
+pub trait Parse: Sized {
+    fn parse_str(input: &str) -> Result<Self, ParseError>;
+}
+
+/// Parses with `FromStr`, reporting its error as [`ParseError::Malformed`].
+fn from_str<T>(input: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input.parse().map_err(|e: T::Err| ParseError::Malformed {
+        input: input.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+macro_rules! impl_parse_via_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Parse for $ty {
+                fn parse_str(input: &str) -> Result<Self, ParseError> {
+                    from_str(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_via_from_str!(
+    String,
+    PathBuf,
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    SocketAddr,
+);
@@ -0,0 +1,308 @@
+//! Parsers for filesystem paths.
+
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, OpenOptions},
+    io,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{NonEmptyVec, Parse, ParseError};
+
+/// A comma-separated list of at least one non-blank path, e.g. `CONFIG_DIRS`.
+///
+/// Each segment is trimmed, and blank segments are rejected rather than
+/// becoming an empty `PathBuf`. A comma inside a path is written `\,`.
+/// Backslashes are only special before a comma, where `2n` of them stand for
+/// `n` backslashes and a separator, and `2n + 1` for `n` backslashes and a
+/// comma; so `a\\\,b` is the single path `a\,b`. Any other backslash, as in
+/// `\\server\share`, is kept as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathList(NonEmptyVec<PathBuf>);
+
+impl PathList {
+    pub const SEPARATOR: char = ',';
+
+    pub fn parse(input: &str) -> Result<Self, PathListError> {
+        let (head, tail) = Self::split(input).into_parts();
+
+        let head = Self::parse_segment(&head, 0)?;
+        let tail = tail
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| Self::parse_segment(segment, index + 1))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(NonEmptyVec::new(head, tail)))
+    }
+
+    /// Splits on unescaped separators; like `str::split`, this always yields a segment.
+    fn split(input: &str) -> NonEmptyVec<String> {
+        let mut segments = NonEmptyVec::singleton(String::new());
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    let mut run = 1;
+                    while chars.next_if_eq(&'\\').is_some() {
+                        run += 1;
+                    }
+
+                    // Like Windows argv parsing: before a comma, each `\\` is one
+                    // backslash, and an odd one out escapes the comma.
+                    if chars.peek() == Some(&Self::SEPARATOR) {
+                        segments.last_mut().push_str(&"\\".repeat(run / 2));
+
+                        if run % 2 == 1 {
+                            segments.last_mut().push(Self::SEPARATOR);
+                            chars.next();
+                        }
+                    } else {
+                        segments.last_mut().push_str(&"\\".repeat(run));
+                    }
+                }
+                Self::SEPARATOR => segments.push(String::new()),
+                c => segments.last_mut().push(c),
+            }
+        }
+
+        segments
+    }
+
+    fn parse_segment(segment: &str, index: usize) -> Result<PathBuf, PathListError> {
+        match segment.trim() {
+            "" => Err(PathListError::EmptySegment { index }),
+            path => Ok(PathBuf::from(path)),
+        }
+    }
+
+    pub fn paths(&self) -> &NonEmptyVec<PathBuf> {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> NonEmptyVec<PathBuf> {
+        self.0
+    }
+}
+
+impl Parse for PathList {
+    fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input).map_err(|e| ParseError::Malformed {
+            input: input.to_string(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Why a [`PathList`] could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathListError {
+    EmptySegment { index: usize },
+}
+
+impl fmt::Display for PathListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySegment { index } => write!(f, "path {index} is empty"),
+        }
+    }
+}
+
+impl Error for PathListError {}
+
+/// A path which is absolute, so its meaning doesn't depend on the working directory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbsolutePath(PathBuf);
+
+impl AbsolutePath {
+    pub fn parse(path: impl Into<PathBuf>) -> Result<Self, PathError> {
+        let path = path.into();
+
+        if path.is_absolute() {
+            Ok(Self(path))
+        } else {
+            Err(PathError::NotAbsolute { path })
+        }
+    }
+
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0
+    }
+}
+
+/// A directory which existed when it was parsed.
+///
+/// The path is canonicalized, so it is also absolute and free of symlinks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExistingDir(PathBuf);
+
+impl ExistingDir {
+    pub fn parse(path: impl Into<PathBuf>) -> Result<Self, PathError> {
+        let path = path.into();
+
+        let canonical = match fs::canonicalize(&path) {
+            Ok(canonical) => canonical,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(PathError::NotFound { path })
+            }
+            Err(source) => return Err(PathError::Io { path, source }),
+        };
+
+        if canonical.is_dir() {
+            Ok(Self(canonical))
+        } else {
+            Err(PathError::NotADirectory { path })
+        }
+    }
+
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0
+    }
+}
+
+/// An existing directory which we were able to create a file in when it was parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WritableDir(ExistingDir);
+
+impl WritableDir {
+    /// Probes for write access by creating, then removing, an empty file in `dir`.
+    pub fn parse(dir: ExistingDir) -> Result<Self, PathError> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.subsec_nanos());
+        let probe = dir.join(format!(".write-probe-{}-{nanos}", process::id()));
+
+        let created = OpenOptions::new().write(true).create_new(true).open(&probe);
+
+        match created {
+            Ok(_) => {
+                fs::remove_file(&probe).map_err(|source| PathError::Io {
+                    path: probe,
+                    source,
+                })?;
+
+                Ok(Self(dir))
+            }
+            Err(source)
+                if matches!(
+                    source.kind(),
+                    io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem
+                ) =>
+            {
+                Err(PathError::NotWritable {
+                    path: dir.into_path_buf(),
+                    source,
+                })
+            }
+            Err(source) => Err(PathError::Io {
+                path: dir.into_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn into_existing_dir(self) -> ExistingDir {
+        self.0
+    }
+}
+
+macro_rules! impl_path_newtype {
+    ($($ty:ty),*) => {
+        $(
+            impl Deref for $ty {
+                type Target = Path;
+
+                fn deref(&self) -> &Path {
+                    &self.0
+                }
+            }
+
+            impl AsRef<Path> for $ty {
+                fn as_ref(&self) -> &Path {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_path_newtype!(AbsolutePath, ExistingDir, WritableDir);
+
+impl From<ExistingDir> for AbsolutePath {
+    fn from(dir: ExistingDir) -> Self {
+        Self(dir.0)
+    }
+}
+
+impl From<WritableDir> for ExistingDir {
+    fn from(dir: WritableDir) -> Self {
+        dir.0
+    }
+}
+
+impl Parse for AbsolutePath {
+    fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input).map_err(|e| e.into_parse_error(input))
+    }
+}
+
+impl Parse for ExistingDir {
+    fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input).map_err(|e| e.into_parse_error(input))
+    }
+}
+
+impl Parse for WritableDir {
+    fn parse_str(input: &str) -> Result<Self, ParseError> {
+        ExistingDir::parse(input)
+            .and_then(Self::parse)
+            .map_err(|e| e.into_parse_error(input))
+    }
+}
+
+/// Why a path could not be parsed into one of the refined path types.
+#[derive(Debug)]
+pub enum PathError {
+    NotAbsolute { path: PathBuf },
+    NotFound { path: PathBuf },
+    NotADirectory { path: PathBuf },
+    NotWritable { path: PathBuf, source: io::Error },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl PathError {
+    fn into_parse_error(self, input: &str) -> ParseError {
+        ParseError::Malformed {
+            input: input.to_string(),
+            reason: self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAbsolute { path } => write!(f, "{} is not absolute", path.display()),
+            Self::NotFound { path } => write!(f, "{} does not exist", path.display()),
+            Self::NotADirectory { path } => write!(f, "{} is not a directory", path.display()),
+            Self::NotWritable { path, source } => {
+                write!(f, "{} is not writable: {source}", path.display())
+            }
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl Error for PathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotWritable { source, .. } | Self::Io { source, .. } => Some(source),
+            Self::NotAbsolute { .. } | Self::NotFound { .. } | Self::NotADirectory { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Values refined by a predicate which is checked once, when they are parsed.
+//!
+//! ```
+//! use non_empty::refined::{And, MaxLen, NonEmpty, Refined};
+//!
+//! type Name = Refined<String, And<NonEmpty, MaxLen<8>>>;
+//!
+//! assert_eq!(Name::parse("cache".to_string()).unwrap().len(), 5);
+//! assert_eq!(
+//!     Name::parse(String::new()).unwrap_err().to_string(),
+//!     "value must be non-empty",
+//! );
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{BoundedVec, Parse, ParseError};
+
+/// A `T` which is known to satisfy `P`.
+///
+/// The only way to get one is [`Refined::parse`], and there is no mutable
+/// access, so the predicate can never stop holding.
+pub struct Refined<T, P>(T, PhantomData<fn() -> P>);
+
+impl<T, P: Predicate<T>> Refined<T, P> {
+    pub fn parse(value: T) -> Result<Self, PredicateError<T>> {
+        match P::check(&value) {
+            Ok(()) => Ok(Self(value, PhantomData)),
+            Err(expected) => Err(PredicateError { value, expected }),
+        }
+    }
+}
+
+impl<T, P> Refined<T, P> {
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// A property of a `T`, used as the `P` of a [`Refined`].
+pub trait Predicate<T: ?Sized> {
+    /// What every value satisfying the predicate must do, e.g. `be positive`.
+    fn describe() -> String;
+
+    fn test(value: &T) -> bool;
+
+    /// Describes the part of the predicate `value` fails, if any.
+    fn check(value: &T) -> Result<(), String> {
+        if Self::test(value) {
+            Ok(())
+        } else {
+            Err(Self::describe())
+        }
+    }
+}
+
+/// A value did not satisfy a predicate.
+///
+/// The value is kept, so a failed parse never loses data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateError<T> {
+    value: T,
+    expected: String,
+}
+
+impl<T> PredicateError<T> {
+    /// The part of the predicate which failed, e.g. `be positive`.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for PredicateError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value must {}", self.expected)
+    }
+}
+
+impl<T: fmt::Debug> Error for PredicateError<T> {}
+
+impl<T: fmt::Debug> From<PredicateError<T>> for ParseError {
+    fn from(error: PredicateError<T>) -> Self {
+        Self::Malformed {
+            input: format!("{:?}", error.value),
+            reason: format!("must {}", error.expected),
+        }
+    }
+}
+
+/// The number of elements in a collection, or of characters in a string.
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+macro_rules! impl_length {
+    ($($ty:ty $(, $generic:ident)*);* $(;)?) => {
+        $(
+            impl<$($generic),*> Length for $ty {
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+impl_length!(
+    [T], T;
+    Vec<T>, T;
+    VecDeque<T>, T;
+    HashSet<T>, T;
+    BTreeSet<T>, T;
+    HashMap<K, V>, K, V;
+    BTreeMap<K, V>, K, V;
+);
+
+impl<T, const MIN: usize, const MAX: usize> Length for BoundedVec<T, MIN, MAX> {
+    fn length(&self) -> usize {
+        self.len().get()
+    }
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T: Length + ?Sized> Length for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Has at least one element or character.
+pub struct NonEmpty;
+
+impl<T: Length + ?Sized> Predicate<T> for NonEmpty {
+    fn describe() -> String {
+        "be non-empty".to_string()
+    }
+
+    fn test(value: &T) -> bool {
+        value.length() > 0
+    }
+}
+
+/// Has at least `N` elements or characters.
+pub struct MinLen<const N: usize>;
+
+impl<T: Length + ?Sized, const N: usize> Predicate<T> for MinLen<N> {
+    fn describe() -> String {
+        format!("have a length of at least {N}")
+    }
+
+    fn test(value: &T) -> bool {
+        value.length() >= N
+    }
+}
+
+/// Has at most `N` elements or characters.
+pub struct MaxLen<const N: usize>;
+
+impl<T: Length + ?Sized, const N: usize> Predicate<T> for MaxLen<N> {
+    fn describe() -> String {
+        format!("have a length of at most {N}")
+    }
+
+    fn test(value: &T) -> bool {
+        value.length() <= N
+    }
+}
+
+/// An integer in `A..=B`.
+pub struct InRange<const A: i128, const B: i128>;
+
+impl<T, const A: i128, const B: i128> Predicate<T> for InRange<A, B>
+where
+    T: Copy + TryInto<i128>,
+{
+    fn describe() -> String {
+        format!("be in {A}..={B}")
+    }
+
+    fn test(value: &T) -> bool {
+        (*value)
+            .try_into()
+            .is_ok_and(|value| (A..=B).contains(&value))
+    }
+}
+
+/// Greater than zero.
+pub struct Positive;
+
+/// Not equal to zero.
+pub struct NonZero;
+
+/// Neither infinite nor NaN.
+pub struct Finite;
+
+macro_rules! impl_number_predicates {
+    ($zero:literal: $($ty:ty),*) => {
+        $(
+            impl Predicate<$ty> for Positive {
+                fn describe() -> String {
+                    "be positive".to_string()
+                }
+
+                fn test(value: &$ty) -> bool {
+                    *value > $zero
+                }
+            }
+
+            impl Predicate<$ty> for NonZero {
+                fn describe() -> String {
+                    "be non-zero".to_string()
+                }
+
+                fn test(value: &$ty) -> bool {
+                    *value != $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_number_predicates!(0: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number_predicates!(0.0: f32, f64);
+
+macro_rules! impl_finite {
+    ($($ty:ty),*) => {
+        $(
+            impl Predicate<$ty> for Finite {
+                fn describe() -> String {
+                    "be finite".to_string()
+                }
+
+                fn test(value: &$ty) -> bool {
+                    value.is_finite()
+                }
+            }
+        )*
+    };
+}
+
+impl_finite!(f32, f64);
+
+/// In non-decreasing order.
+pub struct Sorted;
+
+/// Without any element appearing twice.
+pub struct Distinct;
+
+macro_rules! impl_sequence_predicates {
+    ($($ty:ty $(, const $bound:ident)*);* $(;)?) => {
+        $(
+            impl<T: PartialOrd $(, const $bound: usize)*> Predicate<$ty> for Sorted {
+                fn describe() -> String {
+                    "be sorted".to_string()
+                }
+
+                fn test(value: &$ty) -> bool {
+                    let value: &[T] = value;
+                    value.is_sorted()
+                }
+            }
+
+            impl<T: Eq + Hash $(, const $bound: usize)*> Predicate<$ty> for Distinct {
+                fn describe() -> String {
+                    "have no duplicates".to_string()
+                }
+
+                fn test(value: &$ty) -> bool {
+                    let value: &[T] = value;
+                    let mut seen = HashSet::with_capacity(value.len());
+                    value.iter().all(|element| seen.insert(element))
+                }
+            }
+        )*
+    };
+}
+
+impl_sequence_predicates!(
+    [T];
+    Vec<T>;
+    BoundedVec<T, MIN, MAX>, const MIN, const MAX;
+);
+
+/// Satisfies both `A` and `B`; a failure names only the part which failed.
+pub struct And<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<T: ?Sized, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
+    fn describe() -> String {
+        format!("{} and {}", A::describe(), B::describe())
+    }
+
+    fn test(value: &T) -> bool {
+        A::test(value) && B::test(value)
+    }
+
+    fn check(value: &T) -> Result<(), String> {
+        A::check(value)?;
+        B::check(value)
+    }
+}
+
+/// Satisfies `A`, `B` or both.
+pub struct Or<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<T: ?Sized, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
+    fn describe() -> String {
+        format!("{} or {}", A::describe(), B::describe())
+    }
+
+    fn test(value: &T) -> bool {
+        A::test(value) || B::test(value)
+    }
+}
+
+/// Does not satisfy `A`.
+pub struct Not<A>(PhantomData<fn() -> A>);
+
+impl<T: ?Sized, A: Predicate<T>> Predicate<T> for Not<A> {
+    fn describe() -> String {
+        format!("not {}", A::describe())
+    }
+
+    fn test(value: &T) -> bool {
+        !A::test(value)
+    }
+}
+
+impl<T, P> Deref for Refined<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, P> AsRef<T> for Refined<T, P> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+// The rest are implemented by hand, since deriving would add bounds on `P`.
+impl<T: fmt::Debug, P> fmt::Debug for Refined<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Refined").field(&self.0).finish()
+    }
+}
+
+impl<T: fmt::Display, P> fmt::Display for Refined<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Clone, P> Clone for Refined<T, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Copy, P> Copy for Refined<T, P> {}
+
+impl<T: PartialEq, P> PartialEq for Refined<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, P> Eq for Refined<T, P> {}
+
+impl<T: PartialOrd, P> PartialOrd for Refined<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, P> Ord for Refined<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Hash, P> Hash for Refined<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: Parse, P: Predicate<T>> Parse for Refined<T, P> {
+    fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse(T::parse_str(input)?).map_err(|error| ParseError::Malformed {
+            input: input.to_string(),
+            reason: format!("must {}", error.expected),
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    error::Bounds,
+    refined::{Predicate, Refined},
+    BoundedVec, NonEmptySlice,
+};
+
+impl<T: Serialize> Serialize for NonEmptySlice<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<T: Serialize, const MIN: usize, const MAX: usize> Serialize for BoundedVec<T, MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+/// Deserializes a sequence, failing as soon as it is known to be too short or
+/// too long, so the error points at the offending array.
+impl<'de, T, const MIN: usize, const MAX: usize> Deserialize<'de> for BoundedVec<T, MIN, MAX>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(BoundedVecVisitor(PhantomData))
+    }
+}
+
+struct BoundedVecVisitor<T, const MIN: usize, const MAX: usize>(PhantomData<T>);
+
+impl<'de, T, const MIN: usize, const MAX: usize> Visitor<'de> for BoundedVecVisitor<T, MIN, MAX>
+where
+    T: Deserialize<'de>,
+{
+    type Value = BoundedVec<T, MIN, MAX>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", Bounds(MIN, MAX))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Like serde's own `Vec` impl, trust a length hint for at most 1 MiB up front.
+        let max_preallocation = 1024 * 1024 / size_of::<T>().max(1);
+        let capacity = seq.size_hint().unwrap_or(0).clamp(MIN, MAX);
+        let mut vec = Vec::with_capacity(capacity.min(max_preallocation));
+
+        while let Some(element) = seq.next_element()? {
+            if vec.len() == MAX {
+                return Err(de::Error::invalid_length(MAX + 1, &self));
+            }
+
+            vec.push(element);
+        }
+
+        if vec.len() < MIN {
+            return Err(de::Error::invalid_length(vec.len(), &self));
+        }
+
+        Ok(BoundedVec::from_vec_unchecked(vec))
+    }
+}
+
+impl<T: Serialize, P> Serialize for Refined<T, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, P: Predicate<T>> Deserialize<'de> for Refined<T, P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::parse(T::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
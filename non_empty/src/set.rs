@@ -0,0 +1,104 @@
+use std::{borrow::Borrow, collections::HashSet, hash::Hash, num::NonZeroUsize, ops::Deref};
+
+use crate::{EmptyError, WouldBeEmptyError};
+
+/// A `HashSet` which always contains at least one element.
+///
+/// Dereferences to the inner `HashSet` for reading; every method which could
+/// remove elements is checked.
+#[derive(Debug, Clone)]
+pub struct NonEmptyHashSet<T>(HashSet<T>);
+
+impl<T: Eq + Hash> NonEmptyHashSet<T> {
+    pub fn singleton(value: T) -> Self {
+        Self(HashSet::from([value]))
+    }
+
+    pub fn from_set(set: HashSet<T>) -> Result<Self, EmptyError<HashSet<T>>> {
+        if set.is_empty() {
+            Err(EmptyError::new(set))
+        } else {
+            Ok(Self(set))
+        }
+    }
+
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    /// The first element in iteration order, which is arbitrary for a `HashSet`.
+    pub fn first(&self) -> &T {
+        self.0.iter().next().expect(NON_EMPTY)
+    }
+
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Removes `value`, unless it is the only element.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, WouldBeEmptyError>
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.0.len() == 1 && self.0.contains(value) {
+            return Err(WouldBeEmptyError);
+        }
+
+        Ok(self.0.remove(value))
+    }
+
+    pub fn into_inner(self) -> HashSet<T> {
+        self.0
+    }
+}
+
+const NON_EMPTY: &str = "a NonEmptyHashSet is never empty";
+
+impl_hashed_eq!(NonEmptyHashSet<T>);
+
+impl<T> Deref for NonEmptyHashSet<T> {
+    type Target = HashSet<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Eq + Hash> TryFrom<HashSet<T>> for NonEmptyHashSet<T> {
+    type Error = EmptyError<HashSet<T>>;
+
+    fn try_from(set: HashSet<T>) -> Result<Self, Self::Error> {
+        Self::from_set(set)
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for NonEmptyHashSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyHashSet<T> {
+    type Item = T;
+    type IntoIter = std::collections::hash_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyHashSet<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::hash_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+pub fn parse_non_empty_hash_set<T: Eq + Hash>(
+    set: HashSet<T>,
+) -> Result<NonEmptyHashSet<T>, EmptyError<HashSet<T>>> {
+    NonEmptyHashSet::from_set(set)
+}
@@ -0,0 +1,154 @@
+use std::{
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{BoundedVec, EmptyError, NonEmptyIter, NonEmptyVec};
+
+/// A slice of at least one element, borrowed as `&NonEmptySlice<T>`.
+///
+/// Like `[T]`, it is unsized, so borrowing one from a [`NonEmptyVec`] never
+/// copies, and a `&NonEmptyVec<T>` deref-coerces to it:
+///
+/// ```
+/// use non_empty::{nonempty, NonEmptySlice};
+///
+/// fn total_head<T>(slice: &NonEmptySlice<T>) -> &T {
+///     slice.head()
+/// }
+///
+/// let vec = nonempty![1, 2, 3];
+/// assert_eq!(total_head(&vec), &1);
+/// ```
+///
+/// It derefs to `[T]` in turn, so slice methods work on both.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySlice<T>([T]);
+
+impl<T> NonEmptySlice<T> {
+    pub fn from_ref(head: &T) -> &Self {
+        Self::from_slice_unchecked(std::slice::from_ref(head))
+    }
+
+    pub fn from_slice(slice: &[T]) -> Result<&Self, EmptyError<&[T]>> {
+        if slice.is_empty() {
+            Err(EmptyError::new(slice))
+        } else {
+            Ok(Self::from_slice_unchecked(slice))
+        }
+    }
+
+    /// The caller must ensure `slice` is not empty.
+    pub(crate) fn from_slice_unchecked(slice: &[T]) -> &Self {
+        debug_assert!(!slice.is_empty());
+
+        // SAFETY: `NonEmptySlice<T>` is a `#[repr(transparent)]` wrapper around
+        // `[T]`, so both pointers have the same layout and metadata.
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// The caller must ensure `slice` is not empty.
+    pub(crate) fn from_mut_slice_unchecked(slice: &mut [T]) -> &mut Self {
+        debug_assert!(!slice.is_empty());
+
+        // SAFETY: as in `from_slice_unchecked`.
+        unsafe { &mut *(slice as *mut [T] as *mut Self) }
+    }
+
+    pub fn head(&self) -> &T {
+        self.first()
+    }
+
+    pub fn tail(&self) -> &[T] {
+        &self.0[1..]
+    }
+
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.0.split_first().expect(NON_EMPTY)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    pub fn first(&self) -> &T {
+        self.0.first().expect(NON_EMPTY)
+    }
+
+    pub fn last(&self) -> &T {
+        self.0.last().expect(NON_EMPTY)
+    }
+
+    pub fn iter(&self) -> NonEmptyIter<std::slice::Iter<'_, T>> {
+        NonEmptyIter::new(self.0.iter())
+    }
+
+    pub fn to_non_empty_vec(&self) -> NonEmptyVec<T>
+    where
+        T: Clone,
+    {
+        NonEmptyVec::from_vec_unchecked(self.0.to_vec())
+    }
+}
+
+const NON_EMPTY: &str = "a NonEmptySlice is never empty";
+
+impl<T> Deref for NonEmptySlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+/// Only the elements can be changed through a `&mut [T]`, never the length.
+impl<T> DerefMut for NonEmptySlice<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptySlice<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
+    type Error = EmptyError<&'a [T]>;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        NonEmptySlice::from_slice(slice)
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> From<&'a BoundedVec<T, MIN, MAX>>
+    for &'a NonEmptySlice<T>
+{
+    fn from(vec: &'a BoundedVec<T, MIN, MAX>) -> Self {
+        vec.as_slice()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptySlice<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
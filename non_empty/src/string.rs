@@ -0,0 +1,198 @@
+use std::{
+    borrow::Borrow,
+    fmt,
+    num::NonZeroUsize,
+    ops::{Add, Deref},
+};
+
+use crate::{BlankError, EmptyError, Parse, ParseError};
+
+/// A `String` which always contains at least one character.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    pub fn from_char(c: char) -> Self {
+        Self(c.to_string())
+    }
+
+    pub fn from_string(string: String) -> Result<Self, EmptyError<String>> {
+        if string.is_empty() {
+            Err(EmptyError::new(string))
+        } else {
+            Ok(Self(string))
+        }
+    }
+
+    /// The length in bytes, like [`str::len`].
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    pub fn first_char(&self) -> char {
+        self.0.chars().next().expect(NON_EMPTY)
+    }
+
+    pub fn last_char(&self) -> char {
+        self.0.chars().next_back().expect(NON_EMPTY)
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.0.push(c)
+    }
+
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+const NON_EMPTY: &str = "a NonEmptyString or NonBlankString is never empty";
+
+/// A `String` which always contains at least one non-whitespace character.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonBlankString(String);
+
+impl NonBlankString {
+    pub fn from_string(string: String) -> Result<Self, BlankError<String>> {
+        if string.trim().is_empty() {
+            Err(BlankError::new(string))
+        } else {
+            Ok(Self(string))
+        }
+    }
+
+    /// The length in bytes, like [`str::len`].
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.0.len()).expect(NON_EMPTY)
+    }
+
+    /// The first character, which may be whitespace.
+    pub fn first_char(&self) -> char {
+        self.0.chars().next().expect(NON_EMPTY)
+    }
+
+    /// Trimming can never remove every character, so the result is still non-blank.
+    pub fn trim(&self) -> NonBlankStr<'_> {
+        NonBlankStr(self.0.trim())
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.0.push(c)
+    }
+
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// A borrowed, trimmed [`NonBlankString`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonBlankStr<'a>(&'a str);
+
+impl NonBlankStr<'_> {
+    pub fn to_non_blank_string(&self) -> NonBlankString {
+        NonBlankString(self.0.to_string())
+    }
+}
+
+macro_rules! impl_string_newtype {
+    ($($ty:ident($error:ident)),*) => {
+        $(
+            impl Deref for $ty {
+                type Target = str;
+
+                fn deref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl AsRef<str> for $ty {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl Borrow<str> for $ty {
+                fn borrow(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl TryFrom<String> for $ty {
+                type Error = $error<String>;
+
+                fn try_from(string: String) -> Result<Self, Self::Error> {
+                    Self::from_string(string)
+                }
+            }
+
+            impl<'a> TryFrom<&'a str> for $ty {
+                type Error = $error<&'a str>;
+
+                fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+                    Self::from_string(string.to_string())
+                        .map_err(|_| $error::new(string))
+                }
+            }
+
+            impl From<$ty> for String {
+                fn from(string: $ty) -> Self {
+                    string.0
+                }
+            }
+
+            impl Parse for $ty {
+                fn parse_str(input: &str) -> Result<Self, ParseError> {
+                    Ok(Self::try_from(input)?)
+                }
+            }
+
+            impl Add<&str> for $ty {
+                type Output = Self;
+
+                fn add(mut self, rhs: &str) -> Self {
+                    self.push_str(rhs);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_string_newtype!(NonEmptyString(EmptyError), NonBlankString(BlankError));
+
+impl Deref for NonBlankStr<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl From<NonBlankString> for NonEmptyString {
+    fn from(string: NonBlankString) -> Self {
+        Self(string.0)
+    }
+}
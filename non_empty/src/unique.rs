@@ -0,0 +1,186 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt,
+    hash::Hash,
+    ops::Deref,
+};
+
+use crate::{NonEmptyVec, ParseError};
+
+/// A `HashMap` parsed from key-value pairs which had no duplicate keys.
+///
+/// Unlike collecting into a `HashMap`, which silently keeps the last value
+/// for a repeated key, parsing rejects the input. For the same reason,
+/// [`UniqueKeyMap::try_insert`] never overwrites a value.
+#[derive(Debug, Clone)]
+pub struct UniqueKeyMap<K, V>(HashMap<K, V>);
+
+/// Like [`UniqueKeyMap`], but ordered by key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueKeyBTreeMap<K, V>(BTreeMap<K, V>);
+
+/// Parses key-value pairs into a map, failing if any key appears more than once.
+pub fn parse_unique_keys<K: Eq + Hash, V>(
+    pairs: Vec<(K, V)>,
+) -> Result<UniqueKeyMap<K, V>, DuplicateKeysError<K, V>> {
+    let mut first_positions = HashMap::with_capacity(pairs.len());
+    let duplicates = find_duplicates(&pairs, |key, index| {
+        *first_positions.entry(key).or_insert(index)
+    });
+
+    match duplicates {
+        Some(duplicates) => Err(DuplicateKeysError { pairs, duplicates }),
+        None => Ok(UniqueKeyMap(pairs.into_iter().collect())),
+    }
+}
+
+/// Like [`parse_unique_keys`], but into a map ordered by key.
+pub fn parse_unique_keys_ordered<K: Ord, V>(
+    pairs: Vec<(K, V)>,
+) -> Result<UniqueKeyBTreeMap<K, V>, DuplicateKeysError<K, V>> {
+    let mut first_positions = BTreeMap::new();
+    let duplicates = find_duplicates(&pairs, |key, index| {
+        *first_positions.entry(key).or_insert(index)
+    });
+
+    match duplicates {
+        Some(duplicates) => Err(DuplicateKeysError { pairs, duplicates }),
+        None => Ok(UniqueKeyBTreeMap(pairs.into_iter().collect())),
+    }
+}
+
+/// `first_position` returns where `key` was first seen, recording `index` if it hasn't been.
+fn find_duplicates<'a, K, V>(
+    pairs: &'a [(K, V)],
+    mut first_position: impl FnMut(&'a K, usize) -> usize,
+) -> Option<NonEmptyVec<(usize, usize)>> {
+    let duplicates = pairs.iter().enumerate().filter_map(|(index, (key, _))| {
+        let first = first_position(key, index);
+        (first != index).then_some((first, index))
+    });
+
+    NonEmptyVec::try_from_iter(duplicates).ok()
+}
+
+impl<K: Eq + Hash, V> UniqueKeyMap<K, V> {
+    /// Inserts `key` unless it is already present, in which case the pair is returned.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if self.0.contains_key(&key) {
+            return Err((key, value));
+        }
+
+        self.0.insert(key, value);
+        Ok(())
+    }
+}
+
+impl<K, V> UniqueKeyMap<K, V> {
+    pub fn into_inner(self) -> HashMap<K, V> {
+        self.0
+    }
+}
+
+impl<K: Ord, V> UniqueKeyBTreeMap<K, V> {
+    /// Inserts `key` unless it is already present, in which case the pair is returned.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if self.0.contains_key(&key) {
+            return Err((key, value));
+        }
+
+        self.0.insert(key, value);
+        Ok(())
+    }
+}
+
+impl<K, V> UniqueKeyBTreeMap<K, V> {
+    pub fn into_inner(self) -> BTreeMap<K, V> {
+        self.0
+    }
+}
+
+impl_hashed_eq!(UniqueKeyMap<K, V>);
+
+impl<K, V> Deref for UniqueKeyMap<K, V> {
+    type Target = HashMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> Deref for UniqueKeyBTreeMap<K, V> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a UniqueKeyMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::collections::hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a UniqueKeyBTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::collections::btree_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Some keys appeared more than once.
+///
+/// Every repeat is reported against the key's first position, and the
+/// original pairs are kept so nothing is lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeysError<K, V> {
+    pairs: Vec<(K, V)>,
+    duplicates: NonEmptyVec<(usize, usize)>,
+}
+
+impl<K, V> DuplicateKeysError<K, V> {
+    /// Each repeated key, with the position it first appeared at and the position it was repeated at.
+    pub fn duplicates(&self) -> impl Iterator<Item = (&K, usize, usize)> {
+        self.duplicates
+            .iter()
+            .into_iter()
+            .map(|&(first, repeat)| (&self.pairs[first].0, first, repeat))
+    }
+
+    pub fn into_inner(self) -> Vec<(K, V)> {
+        self.pairs
+    }
+}
+
+impl<K: fmt::Display, V> fmt::Display for DuplicateKeysError<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("duplicate keys:")?;
+
+        for (i, (key, first, repeat)) in self.duplicates().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}`{key}` at {first} and {repeat}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug + fmt::Display, V: fmt::Debug> Error for DuplicateKeysError<K, V> {}
+
+/// Keeps the first duplicated key; use [`DuplicateKeysError`] directly to see all of them.
+impl<K: fmt::Display, V> From<DuplicateKeysError<K, V>> for ParseError {
+    fn from(error: DuplicateKeysError<K, V>) -> Self {
+        let &(first, _) = error.duplicates.head();
+
+        Self::Duplicate {
+            key: error.pairs[first].0.to_string(),
+        }
+    }
+}
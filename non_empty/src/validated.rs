@@ -0,0 +1,98 @@
+use crate::{NonEmptyIterator, NonEmptyVec};
+
+/// Like `Result`, but combining two failures keeps the errors from both.
+///
+/// `Result` stops at the first error, which is what you want when later steps
+/// depend on earlier ones. When parsing independent fields (e.g. every field
+/// of a config struct) that throws information away; [`Validated::zip`]
+/// instead reports every invalid field at once. The errors are a
+/// [`NonEmptyVec`], since a failure always has at least one reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validated<T, E> {
+    Valid(T),
+    Invalid(NonEmptyVec<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    pub fn invalid(error: E) -> Self {
+        Self::Invalid(NonEmptyVec::singleton(error))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Validated<U, E> {
+        match self {
+            Self::Valid(value) => Validated::Valid(f(value)),
+            Self::Invalid(errors) => Validated::Invalid(errors),
+        }
+    }
+
+    pub fn map_err<F>(self, f: impl FnMut(E) -> F) -> Validated<T, F> {
+        match self {
+            Self::Valid(value) => Validated::Valid(value),
+            Self::Invalid(errors) => Validated::Invalid(errors.into_iter().map(f).collect()),
+        }
+    }
+
+    /// Combines two independent results, keeping the errors from both if both failed.
+    pub fn zip<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+        match (self, other) {
+            (Self::Valid(a), Validated::Valid(b)) => Validated::Valid((a, b)),
+            (Self::Valid(_), Validated::Invalid(errors))
+            | (Self::Invalid(errors), Validated::Valid(_)) => Validated::Invalid(errors),
+            (Self::Invalid(mut errors), Validated::Invalid(more)) => {
+                errors.extend(more);
+                Validated::Invalid(errors)
+            }
+        }
+    }
+
+    pub fn map2<U, V>(self, other: Validated<U, E>, f: impl FnOnce(T, U) -> V) -> Validated<V, E> {
+        self.zip(other).map(|(a, b)| f(a, b))
+    }
+
+    /// Chains a step which depends on this one, so it cannot accumulate errors.
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> Validated<U, E>) -> Validated<U, E> {
+        match self {
+            Self::Valid(value) => f(value),
+            Self::Invalid(errors) => Validated::Invalid(errors),
+        }
+    }
+
+    pub fn into_result(self) -> Result<T, NonEmptyVec<E>> {
+        self.into()
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Validated<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Valid(value),
+            Err(error) => Self::invalid(error),
+        }
+    }
+}
+
+impl<T, E> From<Validated<T, E>> for Result<T, NonEmptyVec<E>> {
+    fn from(validated: Validated<T, E>) -> Self {
+        match validated {
+            Validated::Valid(value) => Ok(value),
+            Validated::Invalid(errors) => Err(errors),
+        }
+    }
+}
+
+/// Collects every value if all are valid, or every error otherwise.
+impl<T, E> FromIterator<Validated<T, E>> for Validated<Vec<T>, E> {
+    fn from_iter<I: IntoIterator<Item = Validated<T, E>>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Validated::Valid(Vec::new()), |acc, item| {
+                acc.map2(item, |mut values, value| {
+                    values.push(value);
+                    values
+                })
+            })
+    }
+}
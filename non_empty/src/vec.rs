@@ -0,0 +1,58 @@
+use crate::{BoundedVec, EmptyError};
+
+/// A `Vec<T>` which always contains at least one element.
+///
+/// This is a [`BoundedVec`] with no maximum, so it can always be extended.
+///
+/// `Option<NonEmptyVec<T>>` uses `Vec`'s niche, so it is the same size as
+/// `Vec<T>`: a pointer, a length and a capacity. Dropping the capacity to get
+/// down to a pointer and a length, as a `Box<[T]>` does, would make every
+/// `push` reallocate, so the extra word is deliberately kept.
+pub type NonEmptyVec<T> = BoundedVec<T, 1, { usize::MAX }>;
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(head: T, tail: Vec<T>) -> Self {
+        let mut vec = Vec::with_capacity(tail.len() + 1);
+        vec.push(head);
+        vec.extend(tail);
+
+        Self::from_vec_unchecked(vec)
+    }
+}
+
+/// Creates a [`NonEmptyVec`] from its elements, like [`vec!`].
+///
+/// ```
+/// use non_empty::{nonempty, NonEmptyVec};
+///
+/// let dirs = nonempty!["/etc/xdg", "/usr/local/etc"];
+/// assert_eq!(dirs, NonEmptyVec::new("/etc/xdg", vec!["/usr/local/etc"]));
+/// ```
+///
+/// Without any elements, it fails to compile:
+///
+/// ```compile_fail
+/// let empty: non_empty::NonEmptyVec<u8> = non_empty::nonempty![];
+/// ```
+#[macro_export]
+macro_rules! nonempty {
+    ($($element:expr),+ $(,)?) => {
+        $crate::NonEmptyVec::from_array([$($element),+])
+    };
+}
+
+pub fn head<T>(vec: NonEmptyVec<T>) -> T {
+    vec.into_head()
+}
+
+pub fn validate_non_empty<T>(vec: Vec<T>) -> Result<(), EmptyError<Vec<T>>> {
+    if vec.is_empty() {
+        Err(EmptyError::new(vec))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn parse_non_empty<T>(vec: Vec<T>) -> Result<NonEmptyVec<T>, EmptyError<Vec<T>>> {
+    NonEmptyVec::from_vec(vec).map_err(|error| EmptyError::new(error.into_inner()))
+}
@@ -0,0 +1,55 @@
+//! Configuration directories from the [XDG Base Directory Specification].
+//!
+//! [XDG Base Directory Specification]: https://specifications.freedesktop.org/basedir-spec/latest/
+
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    path::PathBuf,
+};
+
+use crate::NonEmptyVec;
+
+/// The default for `$XDG_CONFIG_DIRS`, which is what makes the result non-empty.
+pub const DEFAULT_CONFIG_DIRS: &str = "/etc/xdg";
+
+/// Every configuration directory in priority order, from the process environment.
+///
+/// See [`config_dirs_from`].
+pub fn config_dirs() -> NonEmptyVec<PathBuf> {
+    config_dirs_from(|name| env::var_os(name))
+}
+
+/// Every configuration directory in priority order, looking variables up with `var`.
+///
+/// `$XDG_CONFIG_HOME` (default `$HOME/.config`) comes first, followed by the
+/// `:`-separated `$XDG_CONFIG_DIRS` (default `/etc/xdg`). As the spec
+/// requires, relative paths are ignored, and an empty variable is treated as
+/// unset. Every returned path is absolute.
+pub fn config_dirs_from(var: impl Fn(&str) -> Option<OsString>) -> NonEmptyVec<PathBuf> {
+    let config_home = absolute_path(var("XDG_CONFIG_HOME"))
+        .or_else(|| absolute_path(var("HOME")).map(|home| home.join(".config")));
+
+    let mut config_dirs =
+        NonEmptyVec::try_from_iter(absolute_paths(var("XDG_CONFIG_DIRS").as_deref()))
+            .unwrap_or_else(|_| NonEmptyVec::singleton(PathBuf::from(DEFAULT_CONFIG_DIRS)));
+
+    if let Some(config_home) = config_home {
+        config_dirs.insert(0, config_home);
+    }
+
+    config_dirs
+}
+
+/// Unlike `$XDG_CONFIG_DIRS`, `$XDG_CONFIG_HOME` and `$HOME` are single paths,
+/// which may themselves contain a `:`.
+fn absolute_path(value: Option<OsString>) -> Option<PathBuf> {
+    value.map(PathBuf::from).filter(|path| path.is_absolute())
+}
+
+fn absolute_paths(value: Option<&OsStr>) -> impl Iterator<Item = PathBuf> + '_ {
+    value
+        .into_iter()
+        .flat_map(env::split_paths)
+        .filter(|path| path.is_absolute())
+}
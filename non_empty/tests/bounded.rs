@@ -0,0 +1,78 @@
+use non_empty::{BoundedVec, NonEmptyVec, ParseError};
+
+type Replicas = BoundedVec<&'static str, 1, 3>;
+type Quorum = BoundedVec<u8, 3, { usize::MAX }>;
+
+#[test]
+fn parsing_reports_actual_and_required_lengths() {
+    let error = Replicas::from_vec(vec!["a", "b", "c", "d"]).unwrap_err();
+    assert_eq!((error.actual(), error.expected()), (4, 1..=3));
+    assert_eq!(
+        error.to_string(),
+        "expected between 1 and 3 elements, found 4"
+    );
+    assert_eq!(
+        ParseError::from(error.clone()),
+        ParseError::OutOfRange {
+            value: "length 4".into(),
+            range: "1..=3".into(),
+        }
+    );
+    assert_eq!(error.into_inner(), ["a", "b", "c", "d"]);
+
+    let error = Quorum::from_vec(vec![1, 2]).unwrap_err();
+    assert_eq!(error.to_string(), "expected at least 3 elements, found 2");
+
+    let error = NonEmptyVec::<u8>::from_vec(Vec::new()).unwrap_err();
+    assert_eq!(error.to_string(), "expected at least one element, found 0");
+    assert_eq!(ParseError::from(error), ParseError::Empty);
+}
+
+#[test]
+fn collecting_stops_past_the_maximum() {
+    let error = Replicas::try_from_iter(std::iter::repeat("a")).unwrap_err();
+    assert_eq!(error.actual(), 4);
+    assert_eq!(error.into_inner(), ["a"; 4]);
+
+    let mut iter = ["a", "b", "c", "d", "e"].into_iter();
+    assert!(Replicas::try_from_iter(iter.by_ref()).is_err());
+    assert_eq!(iter.next(), Some("e"));
+
+    assert_eq!(
+        Replicas::try_from_iter(["a", "b", "c"]).unwrap()[..],
+        ["a", "b", "c"]
+    );
+}
+
+#[test]
+fn push_and_pop_stay_in_bounds() {
+    let mut replicas = Replicas::singleton("a");
+    assert_eq!(replicas.pop(), None);
+
+    replicas.try_push("b").unwrap();
+    replicas.try_insert(0, "c").unwrap();
+    assert_eq!(replicas.try_push("d").unwrap_err().into_inner(), "d");
+    assert_eq!(replicas.try_insert(0, "e").unwrap_err().into_inner(), "e");
+    assert_eq!(replicas[..], ["c", "a", "b"]);
+
+    let mut quorum = Quorum::from_array([1, 2, 3, 4]);
+    assert_eq!(quorum.pop(), Some(4));
+    assert_eq!(quorum.pop(), None);
+
+    quorum.extend([5, 6]);
+    quorum.truncate(0);
+    assert_eq!(quorum.into_vec(), [1, 2, 3]);
+}
+
+#[test]
+fn deserializing_checks_both_bounds() {
+    let error = serde_json::from_str::<Replicas>(r#"["a", "b", "c", "d"]"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("invalid length 4, expected between 1 and 3 elements"));
+
+    let error = serde_json::from_str::<Quorum>("[1]").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("invalid length 1, expected at least 3 elements"));
+}
@@ -0,0 +1,86 @@
+use std::{fs, path::Path};
+
+use non_empty::{
+    config::{self, ConfigError},
+    NonEmptySlice,
+};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Settings {
+    name: String,
+    cache: Cache,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Cache {
+    max_len: u32,
+    #[serde(default)]
+    dirs: Vec<String>,
+}
+
+#[test]
+fn earlier_directories_override_later_ones() {
+    let user = tempfile::tempdir().unwrap();
+    let missing = tempfile::tempdir().unwrap();
+    let system = tempfile::tempdir().unwrap();
+
+    fs::write(
+        system.path().join("app.toml"),
+        "name = \"system\"\n[cache]\nmax_len = 1\ndirs = [\"a\", \"b\"]\n",
+    )
+    .unwrap();
+    fs::write(user.path().join("app.toml"), "[cache]\nmax_len = 2\n").unwrap();
+
+    let dirs = [user.path(), missing.path(), system.path()];
+    let settings =
+        config::load::<Settings, _>(NonEmptySlice::from_slice(&dirs[..]).unwrap(), "app.toml")
+            .unwrap();
+
+    assert_eq!(
+        settings.value(),
+        &Settings {
+            name: "system".into(),
+            cache: Cache {
+                max_len: 2,
+                dirs: vec!["a".into(), "b".into()],
+            },
+        }
+    );
+
+    let user_file = user.path().join("app.toml");
+    let system_file = system.path().join("app.toml");
+    assert_eq!(settings.source("cache.max_len"), Some(user_file.as_path()));
+    assert_eq!(settings.source("cache.dirs"), Some(system_file.as_path()));
+    assert_eq!(settings.source("name"), Some(system_file.as_path()));
+    assert_eq!(settings.sources().count(), 3);
+}
+
+#[test]
+fn json() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("app.json"),
+        r#"{"name": "json", "cache": {"max_len": 3}}"#,
+    )
+    .unwrap();
+
+    let settings =
+        config::load::<Settings, &Path>(NonEmptySlice::from_ref(&dir.path()), "app.json").unwrap();
+
+    assert_eq!(settings.into_value().cache.max_len, 3);
+}
+
+#[test]
+fn errors_name_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("app.toml"), "name = ").unwrap();
+
+    let error = config::load::<Settings, &Path>(NonEmptySlice::from_ref(&dir.path()), "app.toml")
+        .unwrap_err();
+    assert!(matches!(error, ConfigError::Syntax { ref path, .. } if path.ends_with("app.toml")));
+
+    let error = config::load::<Settings, &Path>(NonEmptySlice::from_ref(&dir.path()), "app.yaml")
+        .unwrap_err();
+    assert!(matches!(error, ConfigError::UnknownFormat { .. }));
+}
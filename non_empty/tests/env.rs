@@ -0,0 +1,72 @@
+use std::{env, path::PathBuf};
+
+use non_empty::{
+    env::{EnvError, EnvVar},
+    ParseError,
+};
+
+// Each test uses its own variables, since tests run in parallel in one process.
+
+#[test]
+fn unset_and_empty_are_distinguished() {
+    assert_eq!(
+        EnvVar::<u16>::new("NON_EMPTY_TEST_UNSET").required(),
+        Err(EnvError::Unset {
+            name: "NON_EMPTY_TEST_UNSET".into()
+        })
+    );
+    assert_eq!(
+        EnvVar::<u16>::new("NON_EMPTY_TEST_UNSET").optional(),
+        Ok(None)
+    );
+
+    env::set_var("NON_EMPTY_TEST_EMPTY", "");
+    assert_eq!(
+        EnvVar::<u16>::new("NON_EMPTY_TEST_EMPTY").optional(),
+        Err(EnvError::Empty {
+            name: "NON_EMPTY_TEST_EMPTY".into()
+        })
+    );
+}
+
+#[test]
+fn scalar() {
+    env::set_var("NON_EMPTY_TEST_PORT", "8080");
+    assert_eq!(
+        EnvVar::<u16>::new("NON_EMPTY_TEST_PORT").required(),
+        Ok(8080)
+    );
+
+    env::set_var("NON_EMPTY_TEST_PORT_INVALID", "http");
+    assert!(matches!(
+        EnvVar::<u16>::new("NON_EMPTY_TEST_PORT_INVALID").required(),
+        Err(EnvError::Invalid {
+            error: ParseError::Malformed { .. },
+            ..
+        })
+    ));
+}
+
+#[test]
+fn list() {
+    env::set_var("NON_EMPTY_TEST_DIRS", "/etc,/usr/etc");
+    let dirs = EnvVar::<PathBuf>::new("NON_EMPTY_TEST_DIRS")
+        .with_separator(',')
+        .required()
+        .unwrap();
+    assert_eq!(
+        dirs.into_vec(),
+        vec![PathBuf::from("/etc"), "/usr/etc".into()]
+    );
+
+    env::set_var("NON_EMPTY_TEST_PORTS", "80,443,https");
+    let error = EnvVar::<u16>::new("NON_EMPTY_TEST_PORTS")
+        .with_separator(',')
+        .required()
+        .unwrap_err();
+    assert!(matches!(error, EnvError::InvalidElement { index: 2, .. }));
+    assert_eq!(
+        error.to_string(),
+        "element 2 of NON_EMPTY_TEST_PORTS is invalid: malformed input `https`: invalid digit found in string"
+    );
+}
@@ -0,0 +1,28 @@
+use non_empty::{split_exact, ParseError, ParseExact};
+
+#[test]
+fn parse_exact_returns_an_array() {
+    let [r, g, b] = vec![255u8, 128, 0].parse_exact().unwrap();
+    assert_eq!((r, g, b), (255, 128, 0));
+
+    let error = vec![255u8, 128].parse_exact::<3>().unwrap_err();
+    assert_eq!((error.actual(), error.expected()), (2, 3..=3));
+    assert_eq!(error.to_string(), "expected exactly 3 elements, found 2");
+    assert_eq!(error.into_inner(), [255, 128]);
+}
+
+#[test]
+fn split_exact_keeps_the_original_input() {
+    assert_eq!(split_exact::<2>("::1", ':').unwrap_err().actual(), 3);
+
+    let error = split_exact::<2>("localhost", ':').unwrap_err();
+    assert_eq!(error.to_string(), "expected exactly 2 elements, found 1");
+    assert_eq!(
+        ParseError::from(error.clone()),
+        ParseError::OutOfRange {
+            value: "length 1".into(),
+            range: "2..=2".into(),
+        }
+    );
+    assert_eq!(error.into_inner(), "localhost");
+}
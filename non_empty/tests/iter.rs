@@ -0,0 +1,61 @@
+use non_empty::{peek_non_empty, NonEmptyIterator, NonEmptyVec};
+
+fn vec() -> NonEmptyVec<u32> {
+    NonEmptyVec::new(3, vec![1, 4, 1, 5])
+}
+
+#[test]
+fn adapters_collect_infallibly() {
+    let doubled: NonEmptyVec<u32> = vec().into_iter().map(|x| x * 2).collect();
+    assert_eq!(doubled, NonEmptyVec::new(6, vec![2, 8, 2, 10]));
+
+    let indexed = vec().iter().copied().rev().enumerate().collect();
+    assert_eq!(
+        indexed,
+        NonEmptyVec::new((0, 5), vec![(1, 1), (2, 4), (3, 1), (4, 3)])
+    );
+
+    let pairs = vec()
+        .into_iter()
+        .zip(NonEmptyVec::new('a', vec!['b']).into_iter())
+        .collect();
+    assert_eq!(pairs, NonEmptyVec::new((3, 'a'), vec![(1, 'b')]));
+
+    let chained = NonEmptyVec::singleton(0)
+        .into_iter()
+        .chain(Vec::new())
+        .collect();
+    assert_eq!(chained, NonEmptyVec::singleton(0));
+}
+
+#[test]
+fn terminal_operations_are_total() {
+    assert_eq!(vec().iter().first(), &3);
+    assert_eq!(vec().iter().last(), &5);
+    assert_eq!(vec().into_iter().max(), 5);
+    assert_eq!(vec().into_iter().min(), 1);
+    assert_eq!(vec().into_iter().reduce(|a, b| a + b), 14);
+}
+
+#[test]
+fn try_from_iter_is_lazy() {
+    let mut pulled = 0;
+    let empty = NonEmptyVec::<u32>::try_from_iter(std::iter::from_fn(|| {
+        pulled += 1;
+        None
+    }));
+    assert_eq!(empty.unwrap_err().into_inner().capacity(), 0);
+    assert_eq!(pulled, 1);
+
+    let vec = NonEmptyVec::try_from_iter("a,b,c".split(',')).unwrap();
+    assert_eq!(vec, NonEmptyVec::new("a", vec!["b", "c"]));
+}
+
+#[test]
+fn peek_non_empty_leaves_the_rest_unconsumed() {
+    let (head, mut rest) = peek_non_empty(1..).unwrap();
+    assert_eq!(head, 1);
+    assert_eq!(rest.next(), Some(2));
+
+    assert!(peek_non_empty(Vec::<u32>::new()).is_none());
+}
@@ -0,0 +1,58 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use non_empty::{
+    parse_non_empty_btree_map, parse_non_empty_hash_map, parse_non_empty_hash_set,
+    NonEmptyBTreeMap, WouldBeEmptyError,
+};
+
+#[test]
+fn empty_collections_are_rejected() {
+    assert!(parse_non_empty_hash_map(HashMap::<u8, u8>::new()).is_err());
+    assert!(parse_non_empty_btree_map(BTreeMap::<u8, u8>::new()).is_err());
+    assert!(parse_non_empty_hash_set(HashSet::<u8>::new()).is_err());
+}
+
+#[test]
+fn the_last_entry_cannot_be_removed() {
+    let mut map = parse_non_empty_hash_map(HashMap::from([("a", 1), ("b", 2)])).unwrap();
+    assert_eq!(map.remove("missing"), Ok(None));
+    assert_eq!(map.remove("a"), Ok(Some(1)));
+    assert_eq!(map.remove("b"), Err(WouldBeEmptyError));
+    assert_eq!(map.first_key_value(), (&"b", &2));
+
+    let mut set = parse_non_empty_hash_set(HashSet::from([1, 2])).unwrap();
+    assert_eq!(set.remove(&1), Ok(true));
+    assert_eq!(set.remove(&2), Err(WouldBeEmptyError));
+    assert_eq!(set.first(), &2);
+    assert_eq!(set.len().get(), 1);
+}
+
+#[test]
+fn btree_map_ends_are_total() {
+    let mut map = NonEmptyBTreeMap::singleton(2, "b");
+    map.extend([(3, "c"), (1, "a")]);
+
+    assert_eq!(map.first_key_value(), (&1, &"a"));
+    assert_eq!(map.last_key_value(), (&3, &"c"));
+    assert_eq!(map.remove(&1), Ok(Some("a")));
+    assert_eq!(map.remove(&3), Ok(Some("c")));
+    assert_eq!(map.remove(&2), Err(WouldBeEmptyError));
+}
+
+#[test]
+fn references_iterate_in_for_loops() {
+    let map = NonEmptyBTreeMap::singleton(1, "a");
+    for (key, value) in &map {
+        assert_eq!((key, value), (&1, &"a"));
+    }
+
+    let map = parse_non_empty_hash_map(HashMap::from([(1, "a")])).unwrap();
+    for (key, value) in &map {
+        assert_eq!((key, value), (&1, &"a"));
+    }
+
+    let set = parse_non_empty_hash_set(HashSet::from([1])).unwrap();
+    for element in &set {
+        assert_eq!(element, &1);
+    }
+}
@@ -0,0 +1,46 @@
+use non_empty::{NonEmptyOps, NonEmptySlice, NonEmptyVec};
+
+#[test]
+fn extremes_match_iterator() {
+    let pairs = [(2, 'a'), (3, 'b'), (1, 'c'), (3, 'd'), (1, 'e')];
+    let slice = NonEmptySlice::from_slice(&pairs[..]).unwrap();
+
+    assert_eq!(
+        slice.max_by_key(|p| p.0),
+        pairs.iter().max_by_key(|p| p.0).unwrap()
+    );
+    assert_eq!(
+        slice.min_by_key(|p| p.0),
+        pairs.iter().min_by_key(|p| p.0).unwrap()
+    );
+    assert_eq!(
+        slice.maximum_by(|a, b| a.0.cmp(&b.0)),
+        pairs.iter().max_by(|a, b| a.0.cmp(&b.0)).unwrap()
+    );
+    assert_eq!(
+        slice.minimum_by(|a, b| a.0.cmp(&b.0)),
+        pairs.iter().min_by(|a, b| a.0.cmp(&b.0)).unwrap()
+    );
+}
+
+#[test]
+fn folds_need_no_initial_value() {
+    let vec = NonEmptyVec::new(1, vec![2, 3, 4]);
+
+    assert_eq!(vec.reduce(|sum, x| sum + x), 10);
+    assert_eq!(
+        vec.fold1(|x| x.to_string(), |s, x| format!("{s}-{x}")),
+        "1-2-3-4"
+    );
+    assert_eq!(NonEmptyVec::singleton(7).reduce(|sum, x| sum + x), 7);
+}
+
+#[test]
+fn vec_and_slice_agree() {
+    let vec = NonEmptyVec::new(5, vec![1, 9, 3]);
+
+    assert_eq!(NonEmptyOps::head(&vec), NonEmptyOps::head(vec.as_slice()));
+    assert_eq!(NonEmptyOps::last(&vec), &3);
+    assert_eq!(vec.maximum(), vec.as_slice().maximum());
+    assert_eq!(vec.minimum(), &1);
+}
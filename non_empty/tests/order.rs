@@ -0,0 +1,61 @@
+use non_empty::{head, parse_non_empty, NonEmptyVec};
+
+/// Every vec of length `1..=max_len` over the alphabet `0..alphabet`, duplicates included.
+fn non_empty_inputs(max_len: usize, alphabet: u8) -> Vec<Vec<u8>> {
+    let mut inputs: Vec<Vec<u8>> = (0..alphabet).map(|x| vec![x]).collect();
+    let mut frontier = inputs.clone();
+
+    for _ in 1..max_len {
+        frontier = frontier
+            .iter()
+            .flat_map(|prefix| {
+                (0..alphabet).map(move |x| {
+                    let mut next = prefix.clone();
+                    next.push(x);
+                    next
+                })
+            })
+            .collect();
+        inputs.extend(frontier.iter().cloned());
+    }
+
+    inputs
+}
+
+#[test]
+fn parse_then_into_vec_round_trips() {
+    for input in non_empty_inputs(5, 3) {
+        let parsed = parse_non_empty(input.clone()).unwrap();
+        assert_eq!(parsed.into_vec(), input);
+    }
+}
+
+#[test]
+fn constructors_agree() {
+    for input in non_empty_inputs(4, 3) {
+        let from_vec = NonEmptyVec::from_vec(input.clone()).unwrap();
+        let try_from = NonEmptyVec::try_from(input.clone()).unwrap();
+        let from_iter = NonEmptyVec::from_iter(input.iter().copied()).unwrap();
+
+        assert_eq!(from_vec, try_from);
+        assert_eq!(from_vec, from_iter);
+        assert_eq!(Vec::from(from_vec), input);
+    }
+}
+
+#[test]
+fn head_is_the_first_element() {
+    for input in non_empty_inputs(4, 3) {
+        assert_eq!(head(parse_non_empty(input.clone()).unwrap()), input[0]);
+    }
+}
+
+#[test]
+fn empty_input_is_rejected() {
+    let empty = Vec::<u8>::with_capacity(8);
+    let recovered = parse_non_empty(empty).unwrap_err().into_inner();
+    assert!(recovered.is_empty() && recovered.capacity() >= 8);
+
+    assert!(NonEmptyVec::try_from(Vec::<u8>::new()).is_err());
+    assert!(NonEmptyVec::from_iter(std::iter::empty::<u8>()).is_err());
+}
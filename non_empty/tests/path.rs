@@ -0,0 +1,61 @@
+use std::fs;
+
+use non_empty::path::{AbsolutePath, ExistingDir, PathError, WritableDir};
+
+#[test]
+fn absolute_path() {
+    assert!(AbsolutePath::parse("/etc").is_ok());
+    assert!(matches!(
+        AbsolutePath::parse("etc"),
+        Err(PathError::NotAbsolute { .. })
+    ));
+}
+
+#[test]
+fn existing_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("file");
+    fs::write(&file, "").unwrap();
+
+    let dir = ExistingDir::parse(temp.path()).unwrap();
+    assert!(dir.is_absolute());
+
+    assert!(matches!(
+        ExistingDir::parse(temp.path().join("missing")),
+        Err(PathError::NotFound { .. })
+    ));
+    assert!(matches!(
+        ExistingDir::parse(file),
+        Err(PathError::NotADirectory { .. })
+    ));
+}
+
+#[test]
+fn writable_dir_leaves_no_probe_behind() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let dir = WritableDir::parse(ExistingDir::parse(temp.path()).unwrap()).unwrap();
+
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn read_only_dir_is_not_writable() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = tempfile::tempdir().unwrap();
+    let read_only = temp.path().join("read_only");
+    fs::create_dir(&read_only).unwrap();
+    fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555)).unwrap();
+
+    // Privileged users (e.g. root in a container) can write anyway.
+    if fs::write(read_only.join("privileged"), "").is_ok() {
+        return;
+    }
+
+    assert!(matches!(
+        WritableDir::parse(ExistingDir::parse(&read_only).unwrap()),
+        Err(PathError::NotWritable { .. })
+    ));
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use non_empty::path::{PathList, PathListError};
+
+fn paths(input: &str) -> Vec<PathBuf> {
+    PathList::parse(input).unwrap().into_inner().into_vec()
+}
+
+#[test]
+fn segments_are_trimmed() {
+    assert_eq!(
+        paths(" /etc , /usr/etc"),
+        vec![PathBuf::from("/etc"), "/usr/etc".into()]
+    );
+}
+
+#[test]
+fn empty_segments_are_rejected_by_index() {
+    for (input, index) in [
+        ("", 0),
+        ("  ", 0),
+        ("a,,b", 1),
+        ("a, ,b", 1),
+        ("a,b,", 2),
+        (",a", 0),
+    ] {
+        assert_eq!(
+            PathList::parse(input),
+            Err(PathListError::EmptySegment { index }),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn escapes() {
+    assert_eq!(paths(r"/a\,b,/c"), vec![PathBuf::from("/a,b"), "/c".into()]);
+    assert_eq!(
+        paths(r"C:\dir\\,/c"),
+        vec![PathBuf::from(r"C:\dir\"), "/c".into()]
+    );
+    assert_eq!(paths(r"C:\Users\me"), vec![PathBuf::from(r"C:\Users\me")]);
+    assert_eq!(paths(r"a\\\,b"), vec![PathBuf::from(r"a\,b")]);
+    assert_eq!(paths(r"a\\\\,b"), vec![PathBuf::from(r"a\\"), "b".into()]);
+    assert_eq!(
+        paths(r"\\server\share,\\host\dir\\,/c"),
+        vec![
+            PathBuf::from(r"\\server\share"),
+            r"\\host\dir\".into(),
+            "/c".into()
+        ]
+    );
+}
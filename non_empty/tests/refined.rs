@@ -0,0 +1,62 @@
+use non_empty::{
+    refined::{
+        And, Distinct, Finite, InRange, MaxLen, MinLen, NonEmpty, NonZero, Not, Or, Positive,
+        Refined, Sorted,
+    },
+    Parse, ParseError,
+};
+use serde::Deserialize;
+
+type Port = Refined<u16, InRange<1, 65535>>;
+type Quorum = Refined<Vec<u8>, And<MinLen<3>, And<Sorted, Distinct>>>;
+
+#[test]
+fn parse_is_the_only_constructor() {
+    assert_eq!(*Port::parse(8080).unwrap(), 8080);
+    assert_eq!(
+        Port::parse(0).unwrap_err().to_string(),
+        "value must be in 1..=65535"
+    );
+    assert_eq!(Port::parse(0).unwrap_err().into_inner(), 0);
+
+    assert!(Refined::<f64, And<Finite, Positive>>::parse(0.5).is_ok());
+    assert!(Refined::<f64, Finite>::parse(f64::NAN).is_err());
+    assert!(Refined::<i8, NonZero>::parse(0).is_err());
+    assert!(Refined::<&str, MaxLen<3>>::parse("héé").is_ok());
+}
+
+#[test]
+fn failures_describe_the_part_which_failed() {
+    assert!(Quorum::parse(vec![1, 2, 3]).is_ok());
+
+    let expected = |vec: Vec<u8>| Quorum::parse(vec).unwrap_err().expected().to_string();
+    assert_eq!(expected(vec![1, 2]), "have a length of at least 3");
+    assert_eq!(expected(vec![2, 1, 3]), "be sorted");
+    assert_eq!(expected(vec![1, 1, 3]), "have no duplicates");
+
+    type Blankish = Refined<String, Or<Not<NonEmpty>, MaxLen<1>>>;
+    assert_eq!(
+        Blankish::parse("ab".to_string()).unwrap_err().to_string(),
+        "value must not be non-empty or have a length of at most 1"
+    );
+}
+
+#[test]
+fn parses_from_strings_and_serde() {
+    assert_eq!(
+        Port::parse_str("0"),
+        Err(ParseError::Malformed {
+            input: "0".into(),
+            reason: "must be in 1..=65535".into(),
+        })
+    );
+
+    #[derive(Debug, Deserialize)]
+    struct Settings {
+        #[allow(dead_code)]
+        port: Port,
+    }
+
+    let error = serde_json::from_str::<Settings>(r#"{"port": 0}"#).unwrap_err();
+    assert!(error.to_string().starts_with("value must be in 1..=65535"));
+}
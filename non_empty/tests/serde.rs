@@ -0,0 +1,61 @@
+use non_empty::NonEmptyVec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    replicas: NonEmptyVec<String>,
+}
+
+#[test]
+fn round_trips_through_json() {
+    let json = r#"{"name":"cache","replicas":["a","b"]}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+
+    assert_eq!(config.replicas.head(), "a");
+    assert_eq!(serde_json::to_string(&config).unwrap(), json);
+}
+
+#[test]
+fn empty_json_array_is_rejected_in_place() {
+    let json = "{\n  \"name\": \"cache\",\n  \"replicas\": []\n}";
+    let error = serde_json::from_str::<Config>(json).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "invalid length 0, expected at least one element at line 3 column 16"
+    );
+}
+
+#[test]
+fn empty_toml_array_is_rejected_in_place() {
+    let error = toml::from_str::<Config>("name = \"cache\"\nreplicas = []\n").unwrap_err();
+
+    assert!(error.message().contains("expected at least one element"));
+    assert_eq!(error.span(), Some(26..28));
+}
+
+/// Claims to hold far more elements than it yields, like a hostile length prefix.
+struct OverReported(std::vec::IntoIter<u64>);
+
+impl Iterator for OverReported {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX / 2, Some(usize::MAX / 2))
+    }
+}
+
+#[test]
+fn length_hints_are_not_trusted() {
+    use serde::de::value::{Error, SeqDeserializer};
+
+    let deserializer = SeqDeserializer::<_, Error>::new(OverReported(vec![1, 2].into_iter()));
+    let vec = NonEmptyVec::<u64>::deserialize(deserializer).unwrap();
+
+    assert_eq!(vec, NonEmptyVec::new(1, vec![2]));
+}
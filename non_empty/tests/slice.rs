@@ -0,0 +1,50 @@
+use non_empty::{nonempty, NonEmptyIterator, NonEmptySlice, NonEmptyVec};
+
+#[test]
+fn empty_slices_are_rejected() {
+    let empty: &[u8] = &[];
+    let error = NonEmptySlice::from_slice(empty).unwrap_err();
+    assert_eq!(error.into_inner(), empty);
+
+    let error = <&NonEmptySlice<u8>>::try_from(empty).unwrap_err();
+    assert_eq!(error.into_inner(), empty);
+}
+
+#[test]
+fn accessors() {
+    let elements = [1, 2, 3];
+    let slice = NonEmptySlice::from_slice(&elements[..]).unwrap();
+
+    assert_eq!(slice.head(), &1);
+    assert_eq!(slice.tail(), &[2, 3]);
+    assert_eq!(slice.split_first(), (&1, &[2, 3][..]));
+    assert_eq!(slice.last(), &3);
+    assert_eq!(slice.len().get(), 3);
+    assert_eq!(slice.iter().max(), &3);
+
+    let single = NonEmptySlice::from_ref(&elements[0]);
+    assert_eq!(single.split_first(), (&1, &[][..]));
+    assert!(single.tail().is_empty());
+}
+
+#[test]
+fn vecs_deref_to_slices_without_copying() {
+    fn total_head<T>(slice: &NonEmptySlice<T>) -> &T {
+        slice.head()
+    }
+
+    let mut vec = nonempty![String::from("b"), String::from("a")];
+    assert!(std::ptr::eq(total_head(&vec), vec.head()));
+    assert_eq!(vec.to_non_empty_vec(), vec);
+    assert_eq!(
+        NonEmptySlice::from_ref(&0).to_non_empty_vec(),
+        NonEmptyVec::singleton(0)
+    );
+
+    vec.sort();
+    let [first, rest @ ..] = &vec[..] else {
+        unreachable!()
+    };
+    assert_eq!((first.as_str(), rest.len()), ("a", 1));
+    assert_eq!(vec.binary_search(&"b".to_string()), Ok(1));
+}
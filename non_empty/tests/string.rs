@@ -0,0 +1,38 @@
+use non_empty::{NonBlankString, NonEmptyString, Parse, ParseError};
+
+#[test]
+fn non_empty_string() {
+    assert_eq!(NonEmptyString::try_from("").unwrap_err().into_inner(), "");
+    assert!(NonEmptyString::try_from(" ").is_ok());
+
+    let name = NonEmptyString::try_from("cache".to_string()).unwrap();
+    assert_eq!(name.first_char(), 'c');
+    assert_eq!(name.last_char(), 'e');
+    assert!(name.starts_with("ca"));
+
+    let name = name + "-v1";
+    assert_eq!(name.as_str(), "cache-v1");
+    assert_eq!(name.len().get(), 8);
+}
+
+#[test]
+fn non_blank_string() {
+    assert_eq!(
+        NonBlankString::try_from(" \t").unwrap_err().into_inner(),
+        " \t"
+    );
+    assert_eq!(
+        NonBlankString::parse_str("  "),
+        Err(ParseError::Malformed {
+            input: "  ".into(),
+            reason: "expected at least one non-whitespace character".into(),
+        })
+    );
+
+    let mut name = NonBlankString::parse_str(" cache ").unwrap();
+    assert_eq!(name.first_char(), ' ');
+    assert_eq!(&*name.trim(), "cache");
+
+    name.push_str("\n");
+    assert_eq!(NonEmptyString::from(name).to_string(), " cache \n");
+}
@@ -0,0 +1,53 @@
+use non_empty::{parse_unique_keys, parse_unique_keys_ordered, ParseError};
+
+#[test]
+fn unique_keys_parse() {
+    let map = parse_unique_keys(vec![("a", 1), ("b", 2)]).unwrap();
+    assert_eq!(map.get("b"), Some(&2));
+
+    let map = parse_unique_keys_ordered(vec![("b", 2), ("a", 1)]).unwrap();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+
+    let mut keys = Vec::new();
+    for (key, _) in &map {
+        keys.push(*key);
+    }
+    assert_eq!(keys, ["a", "b"]);
+
+    for (key, value) in &parse_unique_keys(vec![("c", 3)]).unwrap() {
+        assert_eq!((key, value), (&"c", &3));
+    }
+}
+
+#[test]
+fn every_duplicate_is_reported_with_both_positions() {
+    let pairs = vec![("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5), ("a", 6)];
+
+    for error in [
+        parse_unique_keys(pairs.clone()).unwrap_err(),
+        parse_unique_keys_ordered(pairs.clone()).unwrap_err(),
+    ] {
+        assert_eq!(
+            error.duplicates().collect::<Vec<_>>(),
+            vec![(&"a", 0, 2), (&"b", 1, 4), (&"a", 0, 5)]
+        );
+        assert_eq!(
+            error.to_string(),
+            "duplicate keys: `a` at 0 and 2, `b` at 1 and 4, `a` at 0 and 5"
+        );
+        assert_eq!(
+            ParseError::from(error.clone()),
+            ParseError::Duplicate { key: "a".into() }
+        );
+        assert_eq!(error.into_inner(), pairs);
+    }
+}
+
+#[test]
+fn try_insert_never_overwrites() {
+    let mut map = parse_unique_keys(vec![("a", 1)]).unwrap();
+
+    assert_eq!(map.try_insert("a", 2), Err(("a", 2)));
+    assert_eq!(map.try_insert("b", 2), Ok(()));
+    assert_eq!(map.into_inner().len(), 2);
+}
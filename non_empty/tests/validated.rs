@@ -0,0 +1,80 @@
+use non_empty::{parse_non_empty, NonEmptyVec, ParseError, Validated};
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    name: String,
+    port: u16,
+    replicas: NonEmptyVec<String>,
+}
+
+fn parse_name(name: &str) -> Validated<String, ParseError> {
+    match name.trim() {
+        "" => Validated::invalid(ParseError::Empty),
+        name => Validated::Valid(name.to_string()),
+    }
+}
+
+fn parse_port(port: &str) -> Validated<u16, ParseError> {
+    port.parse()
+        .map_err(|e: std::num::ParseIntError| ParseError::Malformed {
+            input: port.to_string(),
+            reason: e.to_string(),
+        })
+        .into()
+}
+
+fn parse_replicas(replicas: Vec<String>) -> Validated<NonEmptyVec<String>, ParseError> {
+    parse_non_empty(replicas).map_err(ParseError::from).into()
+}
+
+fn parse_config(
+    name: &str,
+    port: &str,
+    replicas: Vec<String>,
+) -> Result<Config, NonEmptyVec<ParseError>> {
+    parse_name(name)
+        .zip(parse_port(port))
+        .map2(parse_replicas(replicas), |(name, port), replicas| Config {
+            name,
+            port,
+            replicas,
+        })
+        .into_result()
+}
+
+#[test]
+fn valid_config_parses() {
+    let config = parse_config("cache", "8080", vec!["a".into()]).unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.replicas.head(), "a");
+}
+
+#[test]
+fn every_invalid_field_is_reported() {
+    let errors = parse_config(" ", "http", vec![]).unwrap_err();
+
+    assert_eq!(
+        errors.into_vec(),
+        vec![
+            ParseError::Empty,
+            ParseError::Malformed {
+                input: "http".into(),
+                reason: "invalid digit found in string".into(),
+            },
+            ParseError::Empty,
+        ]
+    );
+}
+
+#[test]
+fn collecting_keeps_every_error() {
+    let ports: Validated<Vec<u16>, ParseError> =
+        ["1", "x", "3", "y"].into_iter().map(parse_port).collect();
+    let Validated::Invalid(errors) = ports else {
+        panic!("expected errors");
+    };
+    assert_eq!(errors.len().get(), 2);
+
+    let ports: Validated<Vec<u16>, ParseError> = ["1", "2"].into_iter().map(parse_port).collect();
+    assert_eq!(ports, Validated::Valid(vec![1, 2]));
+}
@@ -0,0 +1,118 @@
+use non_empty::{nonempty, NonEmptyVec};
+
+fn inputs() -> Vec<Vec<(u8, usize)>> {
+    // Pairs of (key, original position), so stability is observable when sorting by key.
+    [
+        vec![3],
+        vec![1, 1],
+        vec![2, 1, 2, 0, 1],
+        vec![0, 0, 1, 1, 0],
+        vec![4, 3, 2, 1, 0],
+        vec![1, 2, 3, 4, 5],
+        vec![2, 2, 2, 1, 3, 2],
+    ]
+    .into_iter()
+    .map(|keys| keys.into_iter().zip(0..).collect())
+    .collect()
+}
+
+#[test]
+fn sort_by_key_matches_vec() {
+    for input in inputs() {
+        let mut expected = input.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut vec = NonEmptyVec::from_vec(input).unwrap();
+        vec.sort_by_key(|&(key, _)| key);
+
+        assert_eq!(vec.into_vec(), expected);
+    }
+}
+
+#[test]
+fn dedup_by_key_matches_vec() {
+    for input in inputs() {
+        let mut expected = input.clone();
+        expected.dedup_by_key(|&mut (key, _)| key);
+
+        let mut vec = NonEmptyVec::from_vec(input).unwrap();
+        vec.dedup_by_key(|&mut (key, _)| key);
+
+        assert_eq!(vec.into_vec(), expected);
+    }
+}
+
+#[test]
+fn insert_matches_vec() {
+    for input in inputs() {
+        for index in 0..=input.len() {
+            let mut expected = input.clone();
+            expected.insert(index, (9, 9));
+
+            let mut vec = NonEmptyVec::from_vec(input.clone()).unwrap();
+            vec.insert(index, (9, 9));
+
+            assert_eq!(vec.into_vec(), expected);
+        }
+    }
+}
+
+#[test]
+fn never_shrinks_below_one_element() {
+    let mut vec = NonEmptyVec::from_vec(vec![1, 2, 3]).unwrap();
+
+    assert_eq!(vec.pop(), Some(3));
+    assert_eq!(vec.pop(), Some(2));
+    assert_eq!(vec.pop(), None);
+    assert_eq!(vec.len().get(), 1);
+
+    vec.extend([2, 3]);
+    vec.truncate(0);
+    assert_eq!(vec.into_vec(), vec![1]);
+}
+
+#[test]
+fn first_and_last_are_total() {
+    let mut vec = NonEmptyVec::singleton(1);
+    assert_eq!((vec.first(), vec.last()), (&1, &1));
+
+    vec.push(2);
+    assert_eq!((vec.first(), vec.last()), (&1, &2));
+    assert_eq!(vec.get(1), Some(&2));
+    assert_eq!(vec.get(2), None);
+}
+
+#[test]
+fn derefs_to_a_contiguous_slice() {
+    let mut vec = NonEmptyVec::new(3, vec![1, 2]);
+    vec.sort();
+    assert_eq!(vec.binary_search(&2), Ok(1));
+
+    let [head, rest @ ..] = &vec[..] else {
+        unreachable!()
+    };
+    assert_eq!((head, rest), (&1, &[2, 3][..]));
+
+    let slice = vec.as_slice();
+    assert_eq!((slice.first(), &slice[1..]), (&1, &[2, 3][..]));
+}
+
+#[test]
+fn option_uses_the_vec_niche() {
+    use std::mem::size_of;
+
+    // A pointer, a length and a capacity; see the `NonEmptyVec` docs for why
+    // the capacity stays.
+    assert_eq!(size_of::<Option<NonEmptyVec<u8>>>(), size_of::<Vec<u8>>());
+    assert_eq!(size_of::<Vec<u8>>(), 3 * size_of::<usize>());
+}
+
+#[test]
+fn literals_need_no_parsing() {
+    assert_eq!(nonempty![1], NonEmptyVec::singleton(1));
+    assert_eq!(nonempty![1, 2, 3,], NonEmptyVec::new(1, vec![2, 3]));
+    assert_eq!(
+        NonEmptyVec::from_array(["a", "b"]),
+        NonEmptyVec::new("a", vec!["b"])
+    );
+}
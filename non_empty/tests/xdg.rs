@@ -0,0 +1,69 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use non_empty::xdg::config_dirs_from;
+
+fn config_dirs(vars: &[(&str, &str)]) -> Vec<PathBuf> {
+    config_dirs_from(|name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| OsString::from(value))
+    })
+    .into_vec()
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn defaults() {
+    assert_eq!(
+        config_dirs(&[("HOME", "/home/me")]),
+        paths(&["/home/me/.config", "/etc/xdg"])
+    );
+    assert_eq!(config_dirs(&[]), paths(&["/etc/xdg"]));
+}
+
+#[test]
+fn variables_override_defaults() {
+    assert_eq!(
+        config_dirs(&[
+            ("HOME", "/home/me"),
+            ("XDG_CONFIG_HOME", "/cfg"),
+            ("XDG_CONFIG_DIRS", "/a:/b"),
+        ]),
+        paths(&["/cfg", "/a", "/b"])
+    );
+}
+
+#[test]
+fn empty_and_relative_entries_are_ignored() {
+    assert_eq!(
+        config_dirs(&[
+            ("HOME", "/home/me"),
+            ("XDG_CONFIG_HOME", "relative"),
+            ("XDG_CONFIG_DIRS", "relative:/a::b"),
+        ]),
+        paths(&["/home/me/.config", "/a"])
+    );
+    assert_eq!(
+        config_dirs(&[
+            ("HOME", ""),
+            ("XDG_CONFIG_HOME", ""),
+            ("XDG_CONFIG_DIRS", "")
+        ]),
+        paths(&["/etc/xdg"])
+    );
+}
+
+#[test]
+fn only_config_dirs_is_a_list() {
+    assert_eq!(
+        config_dirs(&[("HOME", "/home/a:b")]),
+        paths(&["/home/a:b/.config", "/etc/xdg"])
+    );
+    assert_eq!(
+        config_dirs(&[("XDG_CONFIG_HOME", "/cfg:/other")]),
+        paths(&["/cfg:/other", "/etc/xdg"])
+    );
+}
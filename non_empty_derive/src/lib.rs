@@ -0,0 +1,229 @@
+//! `#[derive(Parse)]` for domain newtypes.
+//!
+//! Re-exported by `non_empty` behind its `derive` feature; see the docs there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Field, Fields, LitStr,
+    Path, Visibility,
+};
+
+/// Generates a smart constructor for a struct wrapping a single private field.
+///
+/// The struct gets an inherent `parse(inner) -> Result<Self, ParseError>`, a
+/// matching `TryFrom<Inner>` impl, a getter (`get()` for tuple structs, the
+/// field's name otherwise) and `into_inner()`. If the inner type implements
+/// the `Parse` trait, so does the struct. No other way to construct the type
+/// is generated, and the field must be private.
+///
+/// Each `#[parse(..)]` check is applied in the order it is written:
+///
+/// - `non_empty` rejects values whose `is_empty()` is true.
+/// - `range = <expr>` rejects values not contained in the range.
+/// - `regex = "<pattern>"` rejects values whose `AsRef<str>` does not match.
+///   Requires the `regex` feature of `non_empty`.
+/// - `with = <path>` calls `fn(Inner) -> Result<Inner, ParseError>`, which
+///   may also normalize the value.
+///
+/// ```
+/// use non_empty::Parse;
+///
+/// #[derive(Parse)]
+/// #[parse(range = 1..=65535)]
+/// struct Port(u32);
+///
+/// assert!(Port::parse(0).is_err());
+/// assert_eq!(Port::parse(8080).unwrap().get(), &8080);
+/// ```
+///
+/// A public field would let callers skip `parse`, so it is rejected:
+///
+/// ```compile_fail
+/// use non_empty::Parse;
+///
+/// #[derive(Parse)]
+/// #[parse(range = 1..=65535)]
+/// struct Port(pub u32);
+/// ```
+#[proc_macro_derive(Parse, attributes(parse))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Check {
+    NonEmpty,
+    Range(Expr),
+    Regex(LitStr),
+    With(Path),
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let field = newtype_field(&input)?;
+    let checks = parse_checks(&input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let inner = &field.ty;
+
+    let (construct, getter) = match &field.ident {
+        Some(ident) => (quote!(Self { #ident: value }), quote!(#ident)),
+        None => (quote!(Self(value)), quote!(get)),
+    };
+    let access = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+
+    let where_predicates = where_clause.map(|clause| &clause.predicates);
+    let checks = checks.iter().map(expand_check);
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn parse(value: #inner) -> ::core::result::Result<Self, ::non_empty::ParseError> {
+                #(#checks)*
+                ::core::result::Result::Ok(#construct)
+            }
+
+            pub fn #getter(&self) -> &#inner {
+                &self.#access
+            }
+
+            pub fn into_inner(self) -> #inner {
+                self.#access
+            }
+        }
+
+        // The higher-ranked bound stops this from being an error when the
+        // inner type doesn't implement `Parse`; the impl just won't apply.
+        impl #impl_generics ::non_empty::Parse for #name #ty_generics
+        where
+            for<'__parse> #inner: ::non_empty::Parse,
+            #where_predicates
+        {
+            fn parse_str(input: &str) -> ::core::result::Result<Self, ::non_empty::ParseError> {
+                Self::parse(<#inner as ::non_empty::Parse>::parse_str(input)?)
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#inner> for #name #ty_generics #where_clause {
+            type Error = ::non_empty::ParseError;
+
+            fn try_from(value: #inner) -> ::core::result::Result<Self, Self::Error> {
+                Self::parse(value)
+            }
+        }
+    })
+}
+
+fn newtype_field(input: &DeriveInput) -> syn::Result<&Field> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "`#[derive(Parse)]` only supports structs",
+            ))
+        }
+    };
+
+    let field = match fields {
+        Fields::Named(named) if named.named.len() == 1 => &named.named[0],
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => &unnamed.unnamed[0],
+        _ => {
+            return Err(Error::new(
+                fields.span(),
+                "`#[derive(Parse)]` only supports structs with exactly one field",
+            ))
+        }
+    };
+
+    if !matches!(field.vis, Visibility::Inherited) {
+        return Err(Error::new(
+            field.vis.span(),
+            "the field of a parsed type must be private, or it could be constructed without being parsed",
+        ));
+    }
+
+    Ok(field)
+}
+
+fn parse_checks(input: &DeriveInput) -> syn::Result<Vec<Check>> {
+    let mut checks = Vec::new();
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("parse"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("non_empty") {
+                checks.push(Check::NonEmpty);
+            } else if meta.path.is_ident("range") {
+                checks.push(Check::Range(meta.value()?.parse()?));
+            } else if meta.path.is_ident("regex") {
+                let pattern: LitStr = meta.value()?.parse()?;
+
+                // Reject bad patterns now, rather than the first time `parse` is called.
+                if let Err(e) = regex::Regex::new(&pattern.value()) {
+                    return Err(Error::new(pattern.span(), e));
+                }
+
+                checks.push(Check::Regex(pattern));
+            } else if meta.path.is_ident("with") {
+                checks.push(Check::With(meta.value()?.parse()?));
+            } else {
+                return Err(meta.error("expected `non_empty`, `range`, `regex` or `with`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(checks)
+}
+
+fn expand_check(check: &Check) -> TokenStream2 {
+    match check {
+        Check::NonEmpty => quote! {
+            if value.is_empty() {
+                return ::core::result::Result::Err(::non_empty::ParseError::Empty);
+            }
+        },
+        Check::Range(range) => quote! {
+            if !(#range).contains(&value) {
+                return ::core::result::Result::Err(::non_empty::ParseError::OutOfRange {
+                    value: ::std::string::ToString::to_string(&value),
+                    range: ::std::format!("{:?}", #range),
+                });
+            }
+        },
+        Check::Regex(pattern) => quote! {
+            {
+                static REGEX: ::std::sync::OnceLock<::non_empty::__private::regex::Regex> =
+                    ::std::sync::OnceLock::new();
+
+                let regex = REGEX.get_or_init(|| {
+                    ::non_empty::__private::regex::Regex::new(#pattern)
+                        .expect("checked by #[derive(Parse)]")
+                });
+                let input = ::core::convert::AsRef::<str>::as_ref(&value);
+
+                if !regex.is_match(input) {
+                    return ::core::result::Result::Err(::non_empty::ParseError::Malformed {
+                        input: ::std::string::ToString::to_string(input),
+                        reason: ::std::format!("does not match `{}`", #pattern),
+                    });
+                }
+            }
+        },
+        Check::With(path) => quote! {
+            let value = #path(value)?;
+        },
+    }
+}
@@ -0,0 +1,79 @@
+use non_empty::{Parse, ParseError};
+
+#[derive(Debug, Parse)]
+#[parse(range = 1..=65535)]
+struct Port(u32);
+
+#[derive(Debug, Parse)]
+#[parse(with = trim, non_empty, regex = "^[a-z][a-z0-9-]*$")]
+struct ServiceName {
+    name: String,
+}
+
+#[derive(Debug, Parse)]
+#[parse(non_empty)]
+struct Replicas<T>(Vec<T>);
+
+#[derive(Debug, Parse)]
+#[parse(non_empty)]
+struct Bytes(Vec<u8>);
+
+fn trim(value: String) -> Result<String, ParseError> {
+    Ok(value.trim().to_string())
+}
+
+#[test]
+fn range() {
+    assert_eq!(Port::parse(8080).unwrap().into_inner(), 8080);
+    assert_eq!(
+        Port::try_from(0).unwrap_err(),
+        ParseError::OutOfRange {
+            value: "0".into(),
+            range: "1..=65535".into(),
+        }
+    );
+}
+
+#[test]
+fn checks_run_in_order() {
+    assert_eq!(
+        ServiceName::parse(" cache ".into()).unwrap().name(),
+        "cache"
+    );
+    assert_eq!(
+        ServiceName::parse("  ".into()).unwrap_err(),
+        ParseError::Empty
+    );
+    assert_eq!(
+        ServiceName::parse("Cache".into()).unwrap_err(),
+        ParseError::Malformed {
+            input: "Cache".into(),
+            reason: "does not match `^[a-z][a-z0-9-]*$`".into(),
+        }
+    );
+}
+
+#[test]
+fn generics() {
+    assert_eq!(Replicas::parse(vec![1, 2]).unwrap().get(), &[1, 2]);
+    assert_eq!(
+        Replicas::<u8>::parse(vec![]).unwrap_err(),
+        ParseError::Empty
+    );
+}
+
+#[test]
+fn parse_trait() {
+    use non_empty::Parse as _;
+
+    assert_eq!(Port::parse_str("443").unwrap().into_inner(), 443);
+    assert!(matches!(
+        Port::parse_str("https"),
+        Err(ParseError::Malformed { .. })
+    ));
+    assert!(matches!(
+        Port::parse_str("0"),
+        Err(ParseError::OutOfRange { .. })
+    ));
+    assert_eq!(Bytes::parse(vec![1]).unwrap().get(), &[1]);
+}